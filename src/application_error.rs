@@ -12,6 +12,7 @@ use std::process::Termination;
 
 use clap::Error as ClapError;
 use sway_workspace_extras::TreeError;
+use sway_workspace_extras::WorkflowError;
 use swayipc::Error as SwayIpcError;
 use swayipc::Fallible;
 use thiserror::Error as ThisError;
@@ -26,6 +27,14 @@ pub enum Error {
     SwayCommand(Vec<SwayIpcError>),
     #[error(transparent)]
     TreeValidation(#[from] TreeError),
+    #[error(transparent)]
+    Workflow(#[from] WorkflowError),
+    #[error("Unknown project '{0}'")]
+    UnknownProject(String),
+    #[error("Failed to access the project state file: {0}")]
+    ProjectState(#[from] std::io::Error),
+    #[error("Neither XDG_STATE_HOME nor HOME is set")]
+    MissingStateDirectory(),
 }
 
 fn format_command_errors(errors: &[SwayIpcError]) -> String {
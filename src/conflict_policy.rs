@@ -0,0 +1,33 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::parse_error::ParseError;
+
+/// What to do if a new workspace number is used on another output.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ConflictPolicy {
+    /// Report an error and don't do anything.
+    Fail,
+    /// Continue with the next number that is not in use.
+    Skip,
+}
+
+impl FromStr for ConflictPolicy {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "fail" => Ok(ConflictPolicy::Fail),
+            "skip" => Ok(ConflictPolicy::Skip),
+            _ => Err(ParseError::UnknownConflictPolicy(value.to_owned())),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use crate::find_all_nodes::FindAllNodes;
+use crate::node_traits::SwayNode;
+use crate::window::{Window, is_window};
+use crate::window_criteria::WindowCriteria;
+
+/// Containers ignored when checking whether a workspace is empty.
+#[derive(Debug, Clone)]
+pub struct EmptinessRules {
+    ignore_sticky: bool,
+    ignored_windows: Vec<WindowCriteria>,
+}
+
+impl EmptinessRules {
+    pub const fn new() -> Self {
+        Self {
+            ignore_sticky: false,
+            ignored_windows: Vec::new(),
+        }
+    }
+
+    pub fn with_ignore_sticky(mut self, ignore_sticky: bool) -> Self {
+        self.ignore_sticky = ignore_sticky;
+        self
+    }
+
+    pub fn with_ignored_windows(mut self, ignored_windows: Vec<WindowCriteria>) -> Self {
+        self.ignored_windows = ignored_windows;
+        self
+    }
+
+    pub(crate) fn ignores<Node: SwayNode>(&self, container: &Node) -> bool {
+        if self.ignore_sticky && container.is_sticky() {
+            return true;
+        }
+
+        let windows = container.find_all_nodes_by(is_window);
+
+        !windows.is_empty()
+            && windows.into_iter().all(|node| {
+                let window = Window::of_node(node);
+                self.ignored_windows.iter().any(|c| c.matches(&window))
+            })
+    }
+}
+
+impl Default for EmptinessRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
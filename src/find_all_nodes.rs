@@ -13,6 +13,10 @@ pub trait FindAllNodes {
     fn find_all_nodes_by<F>(&self, predicate: F) -> Vec<&Self>
     where
         F: Copy + Fn(&Self) -> bool;
+
+    fn find_outermost_nodes_by<F>(&self, predicate: F) -> Vec<&Self>
+    where
+        F: Copy + Fn(&Self) -> bool;
 }
 
 impl<T> FindAllNodes for T
@@ -24,23 +28,39 @@ where
         F: Copy + Fn(&Self) -> bool,
     {
         let mut result_nodes = Vec::<&Self>::new();
-        find_all_nodes_by_rec(self, predicate, &mut result_nodes);
+        find_all_nodes_by_rec(self, predicate, true, &mut result_nodes);
+        result_nodes
+    }
+
+    fn find_outermost_nodes_by<F>(&self, predicate: F) -> Vec<&Self>
+    where
+        F: Copy + Fn(&Self) -> bool,
+    {
+        let mut result_nodes = Vec::<&Self>::new();
+        find_all_nodes_by_rec(self, predicate, false, &mut result_nodes);
         result_nodes
     }
 }
 
-fn find_all_nodes_by_rec<'a, N, F>(node: &'a N, predicate: F, nodes: &mut Vec<&'a N>)
-where
+fn find_all_nodes_by_rec<'a, N, F>(
+    node: &'a N,
+    predicate: F,
+    descend_into_matches: bool,
+    nodes: &mut Vec<&'a N>,
+) where
     N: NodeWithChildren,
     F: Copy + Fn(&N) -> bool,
 {
     if predicate(node) {
-        nodes.push(node)
+        nodes.push(node);
+        if !descend_into_matches {
+            return;
+        }
     }
     node.get_nodes()
         .iter()
         .chain(node.get_floating_nodes().iter())
-        .for_each(|node| find_all_nodes_by_rec(node, predicate, nodes));
+        .for_each(|node| find_all_nodes_by_rec(node, predicate, descend_into_matches, nodes));
 }
 
 #[cfg(test)]
@@ -75,6 +95,15 @@ mod tests {
         assert_eq!(nodes, vec!["n1", "f2"]);
     }
 
+    #[test]
+    fn should_not_collect_nodes_within_outermost_matching_nodes() {
+        let root = given_tree();
+
+        let nodes = root.find_outermost_nodes_by(|n| n == "n1" || n == "n2" || n == "f2");
+
+        assert_eq!(nodes, vec!["n1", "f2"]);
+    }
+
     fn given_tree() -> TestNode {
         TestNode {
             id: "root",
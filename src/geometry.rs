@@ -0,0 +1,126 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::parse_error::ParseError;
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Default)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+impl Rect {
+    pub fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn is_in_direction_of(&self, other: &Rect, direction: Direction) -> bool {
+        match direction {
+            Direction::Left => self.x + self.width <= other.x,
+            Direction::Right => self.x >= other.x + other.width,
+            Direction::Up => self.y + self.height <= other.y,
+            Direction::Down => self.y >= other.y + other.height,
+        }
+    }
+
+    pub fn squared_center_distance(&self, other: &Rect) -> i64 {
+        let (x1, y1) = self.doubled_center();
+        let (x2, y2) = other.doubled_center();
+        let dx = x1 - x2;
+        let dy = y1 - y2;
+        dx * dx + dy * dy
+    }
+
+    fn doubled_center(&self) -> (i64, i64) {
+        (
+            2 * i64::from(self.x) + i64::from(self.width),
+            2 * i64::from(self.y) + i64::from(self.height),
+        )
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "left" => Ok(Direction::Left),
+            "right" => Ok(Direction::Right),
+            "up" => Ok(Direction::Up),
+            "down" => Ok(Direction::Down),
+            _ => Err(ParseError::UnknownDirection(value.to_owned())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rect_right_of_other_is_in_direction_right() {
+        let left = Rect::new(0, 0, 1920, 1080);
+        let right = Rect::new(1920, 0, 1920, 1080);
+
+        assert!(right.is_in_direction_of(&left, Direction::Right));
+        assert!(left.is_in_direction_of(&right, Direction::Left));
+    }
+
+    #[test]
+    fn overlapping_rects_are_in_no_direction() {
+        let first = Rect::new(0, 0, 1920, 1080);
+        let second = Rect::new(1000, 500, 1920, 1080);
+
+        for direction in [
+            Direction::Left,
+            Direction::Right,
+            Direction::Up,
+            Direction::Down,
+        ] {
+            assert!(!second.is_in_direction_of(&first, direction));
+        }
+    }
+
+    #[test]
+    fn rect_below_other_is_in_direction_down() {
+        let top = Rect::new(0, 0, 1920, 1080);
+        let bottom = Rect::new(0, 1080, 1920, 1080);
+
+        assert!(bottom.is_in_direction_of(&top, Direction::Down));
+        assert!(top.is_in_direction_of(&bottom, Direction::Up));
+    }
+
+    #[test]
+    fn parses_known_directions() {
+        assert_eq!("left".parse::<Direction>().unwrap(), Direction::Left);
+        assert_eq!("down".parse::<Direction>().unwrap(), Direction::Down);
+    }
+
+    #[test]
+    fn rejects_unknown_directions() {
+        assert!("sideways".parse::<Direction>().is_err());
+    }
+}
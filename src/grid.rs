@@ -0,0 +1,97 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use crate::geometry::Direction;
+
+/// A cell has the number `first + row * columns + column`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Grid {
+    first: i32,
+    columns: i32,
+}
+
+impl Grid {
+    pub fn new(first: i32, columns: i32) -> Self {
+        Self { first, columns }
+    }
+
+    pub fn position(&self, workspace_number: i32) -> Option<(i32, i32)> {
+        let offset = workspace_number.checked_sub(self.first)?;
+        if offset < 0 || self.columns < 1 {
+            return None;
+        }
+
+        Some((offset / self.columns, offset % self.columns))
+    }
+
+    pub fn number(&self, row: i32, column: i32) -> Option<i32> {
+        row.checked_mul(self.columns)?
+            .checked_add(column)?
+            .checked_add(self.first)
+    }
+
+    pub fn neighbour(&self, workspace_number: i32, direction: Direction) -> Option<i32> {
+        let (row, column) = self.position(workspace_number)?;
+
+        let (row, column) = match direction {
+            Direction::Left => (row, column - 1),
+            Direction::Right => (row, column + 1),
+            Direction::Up => (row - 1, column),
+            Direction::Down => (row + 1, column),
+        };
+
+        if row < 0 || column < 0 || column >= self.columns {
+            return None;
+        }
+
+        self.number(row, column)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn position_of_numbers() {
+        let grid = Grid::new(1, 3);
+
+        assert_eq!(grid.position(1), Some((0, 0)));
+        assert_eq!(grid.position(3), Some((0, 2)));
+        assert_eq!(grid.position(5), Some((1, 1)));
+        assert_eq!(grid.position(0), None);
+    }
+
+    #[test]
+    fn position_relative_to_first_number() {
+        let grid = Grid::new(101, 3);
+
+        assert_eq!(grid.position(101), Some((0, 0)));
+        assert_eq!(grid.position(105), Some((1, 1)));
+    }
+
+    #[test]
+    fn neighbours_within_grid() {
+        let grid = Grid::new(1, 3);
+
+        assert_eq!(grid.neighbour(5, Direction::Left), Some(4));
+        assert_eq!(grid.neighbour(5, Direction::Right), Some(6));
+        assert_eq!(grid.neighbour(5, Direction::Up), Some(2));
+        assert_eq!(grid.neighbour(5, Direction::Down), Some(8));
+    }
+
+    #[test]
+    fn no_neighbours_beyond_edges() {
+        let grid = Grid::new(1, 3);
+
+        assert_eq!(grid.neighbour(4, Direction::Left), None);
+        assert_eq!(grid.neighbour(6, Direction::Right), None);
+        assert_eq!(grid.neighbour(2, Direction::Up), None);
+    }
+}
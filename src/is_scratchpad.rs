@@ -11,6 +11,7 @@ use crate::node_traits::NamedNode;
 
 pub trait IsScratchpad {
     fn is_scratchpad_workspace(&self) -> bool;
+    fn is_scratchpad_output(&self) -> bool;
 }
 
 impl<T> IsScratchpad for T
@@ -23,6 +24,13 @@ where
             .map(|n| n == "__i3_scratch")
             .unwrap_or(false)
     }
+
+    fn is_scratchpad_output(&self) -> bool {
+        self.get_name()
+            .as_ref()
+            .map(|n| n == "__i3")
+            .unwrap_or(false)
+    }
 }
 
 #[cfg(test)]
@@ -56,6 +64,24 @@ mod tests {
         assert!(is_scratchpad);
     }
 
+    #[test]
+    fn node_with_a_random_name_is_not_recognized_as_scratchpad_output() {
+        let node = given_node_with_name("DP-1");
+
+        let is_scratchpad = node.is_scratchpad_output();
+
+        assert!(!is_scratchpad);
+    }
+
+    #[test]
+    fn node_with_the_right_name_is_recognized_as_scratchpad_output() {
+        let node = given_node_with_name("__i3");
+
+        let is_scratchpad = node.is_scratchpad_output();
+
+        assert!(is_scratchpad);
+    }
+
     fn given_node_without_name() -> TestNode {
         TestNode { name: None }
     }
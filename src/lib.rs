@@ -7,14 +7,52 @@
  * received a copy of the license along with this program.
  */
 
+mod conflict_policy;
+mod emptiness_rules;
 mod find_all_nodes;
+mod geometry;
+mod grid;
 mod is_scratchpad;
+mod mark_criteria;
 mod node_traits;
+mod number_range;
+mod output_columns;
+mod output_order;
+mod output_pattern;
+mod output_range;
+mod parse_error;
+mod project;
+mod regex_criteria;
+mod stash;
 mod tree_error;
+mod window;
+mod window_criteria;
 mod workflow;
+mod workflow_error;
 mod workspace;
+mod workspace_assignments;
 
+pub use crate::conflict_policy::ConflictPolicy;
+pub use crate::emptiness_rules::EmptinessRules;
+pub use crate::geometry::{Direction, Rect};
+pub use crate::grid::Grid;
+pub use crate::mark_criteria::MarkCriteria;
 pub use crate::node_traits::{NamedNode, NodeWithChildren, SwayNode};
+pub use crate::number_range::NumberRange;
+pub use crate::output_columns::OutputColumns;
+pub use crate::output_order::sort_by_position;
+pub use crate::output_pattern::OutputPattern;
+pub use crate::output_range::OutputRange;
+pub use crate::parse_error::ParseError;
+pub use crate::project::Project;
+pub use crate::stash::{ScratchpadContainer, StashMark};
 pub use crate::tree_error::TreeError;
+pub use crate::window::Window;
+pub use crate::window_criteria::WindowCriteria;
 pub use crate::workflow::{Action, Workflow};
-pub use crate::workspace::{Workspaces, get_workspaces_of};
+pub use crate::workflow_error::WorkflowError;
+pub use crate::workspace::{
+    Workspace, Workspaces, get_workspaces_excluding_outputs, get_workspaces_of,
+    get_workspaces_with_rules,
+};
+pub use crate::workspace_assignments::WorkspaceAssignments;
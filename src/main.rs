@@ -8,14 +8,18 @@
  */
 
 mod application_error;
+mod project_state;
 
 use application_error::CommandErrorConversion;
 use application_error::Error;
-use clap::{Parser, Subcommand};
+use clap::{Args, Parser, Subcommand};
 use std::process::ExitCode;
 use std::process::Termination;
-use sway_workspace_extras::get_workspaces_of;
-use sway_workspace_extras::{Action, Workflow};
+use sway_workspace_extras::get_workspaces_excluding_outputs;
+use sway_workspace_extras::{
+    Action, ConflictPolicy, Direction, EmptinessRules, MarkCriteria, OutputColumns, OutputPattern,
+    OutputRange, Project, WindowCriteria, Workflow, WorkspaceAssignments,
+};
 use swayipc::Connection;
 
 #[derive(Parser)]
@@ -23,15 +27,231 @@ use swayipc::Connection;
 struct Cli {
     #[clap(subcommand)]
     command: Commands,
+
+    /// Restrict new workspaces of matching outputs to a number range,
+    /// e.g. DP-1=1..=99. Can be given multiple times.
+    #[clap(
+        long = "output-range",
+        value_name = "OUTPUT=START..=END",
+        global = true
+    )]
+    output_ranges: Vec<OutputRange>,
+
+    /// What to do if a new workspace number is used on another output:
+    /// fail or skip. By default those workspaces are shifted.
+    #[clap(long, global = true)]
+    on_conflict: Option<ConflictPolicy>,
+
+    /// Treat workspaces with only sticky windows as empty
+    #[clap(long, global = true)]
+    ignore_sticky: bool,
+
+    /// Treat workspaces with only windows whose app_id, class or title
+    /// matches the regex as empty. Can be given multiple times.
+    #[clap(long = "ignore-window", value_name = "REGEX", global = true)]
+    ignored_windows: Vec<WindowCriteria>,
+
+    /// Define a project with its own range of workspace numbers, e.g.
+    /// frontend=100..=199. Can be given multiple times.
+    #[clap(long = "project", value_name = "NAME=START..=END", global = true)]
+    projects: Vec<Project>,
+
+    /// Arrange the workspaces of matching outputs in a grid with this many
+    /// columns, e.g. DP-1=3. Can be given multiple times.
+    #[clap(long = "columns", value_name = "OUTPUT=COLUMNS", global = true)]
+    grid_columns: Vec<OutputColumns>,
+
+    /// Leave out outputs matching the pattern, e.g. HEADLESS-*. Their
+    /// workspace numbers are never used or renamed. Can be given multiple
+    /// times.
+    #[clap(long = "exclude-output", value_name = "PATTERN", global = true)]
+    excluded_outputs: Vec<OutputPattern>,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     Next,
     Prev,
-    MoveNext,
-    MovePrev,
+    MoveNext {
+        #[clap(flatten)]
+        options: MoveOptions,
+    },
+    MovePrev {
+        #[clap(flatten)]
+        options: MoveOptions,
+    },
+    /// Like next, but continue with the first workspace of the next output
+    /// instead of creating a trailing workspace
+    NextGlobal,
+    /// Like prev, but continue with the last workspace of the previous
+    /// output
+    PrevGlobal,
+    /// Like move-next, but continue with the first workspace of the next
+    /// output instead of creating a trailing workspace
+    MoveNextGlobal {
+        #[clap(flatten)]
+        options: MoveOptions,
+    },
+    /// Like move-prev, but continue with the last workspace of the previous
+    /// output
+    MovePrevGlobal {
+        #[clap(flatten)]
+        options: MoveOptions,
+    },
+    /// Focus the next workspace of the output that exists, skipping gaps
+    NextExisting {
+        /// Continue with the first workspace after the last one
+        #[clap(long)]
+        wrap: bool,
+    },
+    /// Focus the previous workspace of the output that exists, skipping gaps
+    PrevExisting {
+        /// Continue with the last workspace before the first one
+        #[clap(long)]
+        wrap: bool,
+    },
+    /// Focus the next workspace of the output that contains windows
+    NextOccupied {
+        /// Continue with the first workspace after the last one
+        #[clap(long)]
+        wrap: bool,
+    },
+    /// Focus the previous workspace of the output that contains windows
+    PrevOccupied {
+        /// Continue with the last workspace before the first one
+        #[clap(long)]
+        wrap: bool,
+    },
+    /// Focus the nearest empty workspace on the output
+    NextEmpty,
+    /// Focus the nearest workspace with an urgent window
+    NextUrgent,
+    /// Focus a window whose app_id, class or title matches the regex.
+    /// Repeated invocations cycle through all matching windows.
+    GotoApp {
+        criteria: WindowCriteria,
+    },
+    /// Move a window whose app_id, class or title matches the regex to the
+    /// focused workspace
+    MoveHere {
+        criteria: WindowCriteria,
+    },
+    /// Move all windows whose app_id, class or title matches the regex to
+    /// the focused workspace
+    Gather {
+        criteria: WindowCriteria,
+    },
+    /// Move every top-level container except the focused one to its own
+    /// new workspace after the focused one
+    Explode,
+    /// Move all containers to the next workspace of the output
+    MergeNext {
+        /// Rename the successors to close the gap of the removed workspace
+        #[clap(long)]
+        compact: bool,
+    },
+    /// Move all containers to the previous workspace of the output
+    MergePrev {
+        /// Rename the successors to close the gap of the removed workspace
+        #[clap(long)]
+        compact: bool,
+    },
+    /// Move all containers with a mark matching the regex to the focused
+    /// workspace
+    MoveMarkedHere {
+        criteria: MarkCriteria,
+    },
     Shift,
+    /// Like shift but never renames workspaces of other outputs
+    ShiftOutput,
+    MoveToOutputNew {
+        direction: Direction,
+    },
+    /// Switch all outputs with an --output-range to the next group of
+    /// workspaces
+    GroupNext,
+    /// Switch all outputs with an --output-range to the previous group of
+    /// workspaces
+    GroupPrev,
+    /// Switch all outputs with an --output-range to the given group. Group N
+    /// is the N-th number of the range of each output.
+    GroupGoto {
+        #[clap(value_parser = clap::value_parser!(i32).range(1..))]
+        group: i32,
+    },
+    /// Focus the workspace above in the grid
+    Up,
+    /// Focus the workspace below in the grid
+    Down,
+    /// Focus the workspace to the left in the grid
+    Left,
+    /// Focus the workspace to the right in the grid
+    Right,
+    /// Move the focused container to the workspace above in the grid
+    MoveUp,
+    /// Move the focused container to the workspace below in the grid
+    MoveDown,
+    /// Move the focused container to the workspace to the left in the grid
+    MoveLeft,
+    /// Move the focused container to the workspace to the right in the grid
+    MoveRight,
+    /// Activate a project and focus a workspace in its range. Without a name
+    /// the active project is deactivated.
+    ProjectSwitch {
+        name: Option<String>,
+    },
+    /// Send the focused container to the scratchpad and remember the
+    /// workspace it came from
+    Stash,
+    /// Move a container of the scratchpad to a new workspace after the
+    /// focused one, preferring containers stashed from the focused workspace
+    UnstashNext,
+}
+
+#[derive(Args)]
+struct MoveOptions {
+    /// Move the parent of the focused container, e.g. a whole tab group
+    #[clap(long, conflicts_with = "level")]
+    parent: bool,
+
+    /// Move the ancestor this many levels above the focused container
+    #[clap(long, value_name = "N")]
+    level: Option<usize>,
+
+    /// Move the container with this mark instead of the focused one
+    #[clap(long, conflicts_with_all = ["parent", "level"])]
+    mark: Option<String>,
+
+    /// Keep the focus on the current workspace
+    #[clap(long)]
+    no_follow: bool,
+}
+
+impl Commands {
+    fn needs_workspace_assignments(&self) -> bool {
+        !matches!(
+            self,
+            Commands::NextExisting { .. }
+                | Commands::PrevExisting { .. }
+                | Commands::NextOccupied { .. }
+                | Commands::PrevOccupied { .. }
+                | Commands::NextUrgent
+                | Commands::GotoApp { .. }
+                | Commands::MoveHere { .. }
+                | Commands::Gather { .. }
+                | Commands::MergeNext { .. }
+                | Commands::MergePrev { .. }
+                | Commands::MoveMarkedHere { .. }
+                | Commands::ProjectSwitch { .. }
+                | Commands::Stash
+        )
+    }
+}
+
+impl MoveOptions {
+    fn level(&self) -> usize {
+        self.level.unwrap_or(usize::from(self.parent))
+    }
 }
 
 fn main() -> ExitCode {
@@ -46,20 +266,124 @@ fn run_program() -> Result<(), Error> {
 
     let mut connection = Connection::new()?;
     let tree = connection.get_tree()?;
+    let assignments = if cli.command.needs_workspace_assignments() {
+        WorkspaceAssignments::parse(&connection.get_config()?.config)
+    } else {
+        WorkspaceAssignments::default()
+    };
 
-    let workflow = get_workspaces_of(&tree).map(Workflow::new)?;
+    let rules = EmptinessRules::default()
+        .with_ignore_sticky(cli.ignore_sticky)
+        .with_ignored_windows(cli.ignored_windows);
+
+    let workflow = get_workspaces_excluding_outputs(&tree, &rules, &cli.excluded_outputs)
+        .map(Workflow::new)?
+        .with_output_ranges(cli.output_ranges)
+        .with_conflict_policy(cli.on_conflict)
+        .with_grid_columns(cli.grid_columns)
+        .with_workspace_assignments(assignments);
+
+    let active_project = match &cli.command {
+        Commands::ProjectSwitch { name } => {
+            let project = name
+                .as_ref()
+                .map(|name| find_project(&cli.projects, name))
+                .transpose()?;
+            project_state::write_active_project(project.map(Project::name))?;
+            project
+        }
+        _ => project_state::read_active_project()?
+            .and_then(|name| find_project(&cli.projects, &name).ok()),
+    };
+    let workflow = workflow.with_project_range(active_project.map(Project::range));
 
     let actions = match cli.command {
-        Commands::Next => workflow.move_focus_to_next(),
-        Commands::Prev => workflow.move_focus_to_prev(),
-        Commands::MoveNext => workflow.move_container_to_next(),
-        Commands::MovePrev => workflow.move_container_to_prev(),
-        Commands::Shift => workflow.shift_successors(),
+        Commands::Next => workflow.move_focus_to_next()?,
+        Commands::Prev => workflow.move_focus_to_prev()?,
+        Commands::MoveNext { options } => match &options.mark {
+            Some(mark) => workflow
+                .with_follow(!options.no_follow)
+                .move_marked_to_next(mark)?,
+            None => workflow
+                .with_container_level(options.level())
+                .with_follow(!options.no_follow)
+                .move_container_to_next()?,
+        },
+        Commands::MovePrev { options } => match &options.mark {
+            Some(mark) => workflow
+                .with_follow(!options.no_follow)
+                .move_marked_to_prev(mark)?,
+            None => workflow
+                .with_container_level(options.level())
+                .with_follow(!options.no_follow)
+                .move_container_to_prev()?,
+        },
+        Commands::NextGlobal => workflow.move_focus_to_next_global()?,
+        Commands::PrevGlobal => workflow.move_focus_to_prev_global()?,
+        Commands::MoveNextGlobal { options } => match &options.mark {
+            Some(mark) => workflow
+                .with_follow(!options.no_follow)
+                .move_marked_to_next_global(mark)?,
+            None => workflow
+                .with_container_level(options.level())
+                .with_follow(!options.no_follow)
+                .move_container_to_next_global()?,
+        },
+        Commands::MovePrevGlobal { options } => match &options.mark {
+            Some(mark) => workflow
+                .with_follow(!options.no_follow)
+                .move_marked_to_prev_global(mark)?,
+            None => workflow
+                .with_container_level(options.level())
+                .with_follow(!options.no_follow)
+                .move_container_to_prev_global()?,
+        },
+        Commands::NextExisting { wrap } => workflow.move_focus_to_next_existing(wrap),
+        Commands::PrevExisting { wrap } => workflow.move_focus_to_prev_existing(wrap),
+        Commands::NextOccupied { wrap } => workflow.move_focus_to_next_occupied(wrap),
+        Commands::PrevOccupied { wrap } => workflow.move_focus_to_prev_occupied(wrap),
+        Commands::NextEmpty => workflow.move_focus_to_next_empty()?,
+        Commands::NextUrgent => workflow.move_focus_to_next_urgent(),
+        Commands::GotoApp { criteria } => workflow.focus_matching_window(&criteria),
+        Commands::MoveHere { criteria } => workflow.move_matching_window_here(&criteria),
+        Commands::Gather { criteria } => workflow.gather_matching_windows(&criteria),
+        Commands::Explode => workflow.explode()?,
+        Commands::MergeNext { compact } => workflow.merge_into_next(compact),
+        Commands::MergePrev { compact } => workflow.merge_into_prev(compact),
+        Commands::MoveMarkedHere { criteria } => workflow.move_marked_containers_here(&criteria),
+        Commands::Shift => workflow.shift_successors()?,
+        Commands::ShiftOutput => {
+            workflow.shift_successors_on_output(cli.on_conflict.unwrap_or(ConflictPolicy::Fail))?
+        }
+        Commands::MoveToOutputNew { direction } => workflow.move_container_to_output(direction)?,
+        Commands::GroupNext => workflow.focus_next_group(),
+        Commands::GroupPrev => workflow.focus_prev_group(),
+        Commands::GroupGoto { group } => workflow.focus_group(group),
+        Commands::Up => workflow.move_focus_in_grid(Direction::Up)?,
+        Commands::Down => workflow.move_focus_in_grid(Direction::Down)?,
+        Commands::Left => workflow.move_focus_in_grid(Direction::Left)?,
+        Commands::Right => workflow.move_focus_in_grid(Direction::Right)?,
+        Commands::MoveUp => workflow.move_container_in_grid(Direction::Up)?,
+        Commands::MoveDown => workflow.move_container_in_grid(Direction::Down)?,
+        Commands::MoveLeft => workflow.move_container_in_grid(Direction::Left)?,
+        Commands::MoveRight => workflow.move_container_in_grid(Direction::Right)?,
+        Commands::ProjectSwitch { .. } => active_project
+            .map(|project| workflow.move_focus_into_range(project.range()))
+            .unwrap_or_default(),
+        Commands::Stash => workflow.stash(),
+        Commands::UnstashNext => workflow.unstash_next()?,
     };
 
     execute_actions(&mut connection, &actions)
 }
 
+fn find_project<'a>(projects: &'a [Project], name: &str) -> Result<&'a Project, Error> {
+    projects
+        .iter()
+        .find(|project| project.name() == name)
+        .ok_or_else(|| Error::UnknownProject(name.to_owned()))
+}
+
 fn execute_actions(connection: &mut Connection, actions: &[Action]) -> Result<(), Error> {
     if actions.is_empty() {
         return Ok(());
@@ -80,5 +404,24 @@ fn action_to_sway_command(action: &Action) -> String {
             workspace_name,
             new_workspace_name,
         } => format!("rename workspace \"{workspace_name}\" to \"{new_workspace_name}\""),
+        Action::MoveWorkspaceToOutput { output_name } => {
+            format!("move workspace to output \"{output_name}\"")
+        }
+        Action::FocusParent => "focus parent".to_owned(),
+        Action::FocusContainer { con_id } => format!("[con_id={con_id}] focus"),
+        Action::MoveContainerById {
+            con_id,
+            workspace_num,
+        } => format!("[con_id={con_id}] move container to workspace number {workspace_num}"),
+        Action::MarkContainer { con_id, mark } => {
+            format!("[con_id={con_id}] mark --add \"{mark}\"")
+        }
+        Action::UnmarkContainer { con_id, mark } => format!("[con_id={con_id}] unmark \"{mark}\""),
+        Action::MoveContainerToScratchpad { con_id } => {
+            format!("[con_id={con_id}] move container to scratchpad")
+        }
+        Action::ShowScratchpad { con_id } => format!("[con_id={con_id}] scratchpad show"),
+        Action::DisableFloating { con_id } => format!("[con_id={con_id}] floating disable"),
+        Action::FocusOutput { output_name } => format!("focus output \"{output_name}\""),
     }
 }
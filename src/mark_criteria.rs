@@ -0,0 +1,63 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::{ParseError, regex_criteria::RegexCriteria};
+
+/// Matches marks like the `con_mark` criteria of Sway.
+#[derive(Debug, Clone)]
+pub struct MarkCriteria {
+    criteria: RegexCriteria,
+}
+
+impl MarkCriteria {
+    pub fn matches(&self, mark: &str) -> bool {
+        self.criteria.matches(mark)
+    }
+}
+
+impl FromStr for MarkCriteria {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        RegexCriteria::new(s)
+            .map(|criteria| Self { criteria })
+            .map_err(ParseError::InvalidMarkCriteria)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_part_of_mark() {
+        let criteria: MarkCriteria = "term".parse().unwrap();
+
+        assert!(criteria.matches("term"));
+        assert!(criteria.matches("term-2"));
+        assert!(!criteria.matches("browser"));
+    }
+
+    #[test]
+    fn anchored_pattern() {
+        let criteria: MarkCriteria = "^term$".parse().unwrap();
+
+        assert!(criteria.matches("term"));
+        assert!(!criteria.matches("term-2"));
+    }
+
+    #[test]
+    fn invalid_regex() {
+        let criteria = "[term".parse::<MarkCriteria>();
+
+        assert!(matches!(criteria, Err(ParseError::InvalidMarkCriteria(_))));
+    }
+}
@@ -9,6 +9,8 @@
 
 use swayipc::{Node, NodeType};
 
+use crate::geometry::Rect;
+
 pub trait NodeWithChildren: Sized {
     fn get_nodes(&self) -> &Vec<Self>;
     fn get_floating_nodes(&self) -> &Vec<Self>;
@@ -40,6 +42,14 @@ pub trait SwayNode: NodeWithChildren + NamedNode {
     fn is_workspace(&self) -> bool;
     fn is_output(&self) -> bool;
     fn is_focused(&self) -> bool;
+    fn get_focus(&self) -> &[i64];
+    fn is_urgent(&self) -> bool;
+    fn is_visible(&self) -> bool;
+    fn is_sticky(&self) -> bool;
+    fn get_app_id(&self) -> Option<&str>;
+    fn get_window_class(&self) -> Option<&str>;
+    fn get_marks(&self) -> &[String];
+    fn get_rect(&self) -> Rect;
     fn find_as_ref<F>(&self, predicate: F) -> Option<&Self>
     where
         F: Copy + Fn(&Self) -> bool;
@@ -66,6 +76,40 @@ impl SwayNode for Node {
         self.focused
     }
 
+    fn get_focus(&self) -> &[i64] {
+        &self.focus
+    }
+
+    fn is_urgent(&self) -> bool {
+        self.urgent
+    }
+
+    fn is_visible(&self) -> bool {
+        self.visible.unwrap_or(false)
+    }
+
+    fn is_sticky(&self) -> bool {
+        self.sticky
+    }
+
+    fn get_app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    fn get_window_class(&self) -> Option<&str> {
+        self.window_properties
+            .as_ref()
+            .and_then(|properties| properties.class.as_deref())
+    }
+
+    fn get_marks(&self) -> &[String] {
+        &self.marks
+    }
+
+    fn get_rect(&self) -> Rect {
+        Rect::new(self.rect.x, self.rect.y, self.rect.width, self.rect.height)
+    }
+
     fn find_as_ref<F>(&self, predicate: F) -> Option<&Self>
     where
         F: Copy + Fn(&Self) -> bool,
@@ -0,0 +1,110 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::parse_error::ParseError;
+
+/// An inclusive range of workspace numbers written as `start..=end`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct NumberRange {
+    start: i32,
+    end: i32,
+}
+
+impl NumberRange {
+    pub fn new(start: i32, end: i32) -> Self {
+        Self { start, end }
+    }
+
+    pub fn start(&self) -> i32 {
+        self.start
+    }
+
+    pub fn end(&self) -> i32 {
+        self.end
+    }
+
+    pub fn contains(&self, workspace_number: i32) -> bool {
+        self.start <= workspace_number && workspace_number <= self.end
+    }
+
+    pub fn intersection(&self, other: &NumberRange) -> NumberRange {
+        Self::new(self.start.max(other.start), self.end.min(other.end))
+    }
+}
+
+impl FromStr for NumberRange {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidNumberRange(value.to_owned());
+
+        let (start, end) = value.split_once("..=").ok_or_else(invalid)?;
+        let start: i32 = start.trim().parse().map_err(|_| invalid())?;
+        let end: i32 = end.trim().parse().map_err(|_| invalid())?;
+
+        if start < 1 || end < start {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_inclusive_range() {
+        let range: NumberRange = "101..=199".parse().unwrap();
+
+        assert_eq!(range, NumberRange::new(101, 199));
+    }
+
+    #[test]
+    fn rejects_exclusive_range() {
+        assert!("1..99".parse::<NumberRange>().is_err());
+    }
+
+    #[test]
+    fn rejects_empty_range() {
+        assert!("10..=9".parse::<NumberRange>().is_err());
+    }
+
+    #[test]
+    fn rejects_ranges_with_non_positive_numbers() {
+        assert!("0..=9".parse::<NumberRange>().is_err());
+    }
+
+    #[test]
+    fn contains_both_bounds() {
+        let range = NumberRange::new(1, 99);
+
+        assert!(range.contains(1));
+        assert!(range.contains(99));
+        assert!(!range.contains(100));
+    }
+
+    #[test]
+    fn intersection_of_overlapping_ranges() {
+        let range = NumberRange::new(1, 99).intersection(&NumberRange::new(50, 150));
+
+        assert_eq!(range, NumberRange::new(50, 99));
+    }
+
+    #[test]
+    fn intersection_of_disjoint_ranges_is_empty() {
+        let range = NumberRange::new(1, 99).intersection(&NumberRange::new(101, 199));
+
+        assert!(!range.contains(99));
+        assert!(!range.contains(101));
+    }
+}
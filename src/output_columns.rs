@@ -0,0 +1,74 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::output_pattern::OutputPattern;
+use crate::parse_error::ParseError;
+
+/// `OUTPUT=COLUMNS`, e.g. `DP-1=3`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputColumns {
+    pattern: OutputPattern,
+    columns: i32,
+}
+
+impl OutputColumns {
+    pub fn new(pattern: OutputPattern, columns: i32) -> Self {
+        Self { pattern, columns }
+    }
+
+    pub fn matches(&self, output_name: &str) -> bool {
+        self.pattern.matches(output_name)
+    }
+
+    pub fn columns(&self) -> i32 {
+        self.columns
+    }
+}
+
+impl FromStr for OutputColumns {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidOutputColumns(value.to_owned());
+
+        let (pattern, columns) = value.split_once('=').ok_or_else(invalid)?;
+        let columns: i32 = columns.trim().parse().map_err(|_| invalid())?;
+
+        if columns < 1 {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(OutputPattern::new(pattern.trim()), columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_output_and_columns() {
+        let output_columns: OutputColumns = "HDMI-A-1 = 3".parse().unwrap();
+
+        assert!(output_columns.matches("HDMI-A-1"));
+        assert_eq!(output_columns.columns(), 3);
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        assert!("DP-1 3".parse::<OutputColumns>().is_err());
+    }
+
+    #[test]
+    fn rejects_zero_columns() {
+        assert!("DP-1=0".parse::<OutputColumns>().is_err());
+    }
+}
@@ -0,0 +1,44 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use crate::geometry::Rect;
+
+/// Orders outputs row by row, top to bottom and left to right. Outputs
+/// share a row if their top edge is above the bottom edge of the row.
+pub fn sort_by_position<T, F>(items: Vec<T>, rect_of: F) -> Vec<T>
+where
+    F: Fn(&T) -> Rect,
+{
+    let mut items: Vec<_> = items
+        .into_iter()
+        .map(|item| (rect_of(&item), item))
+        .collect();
+    items.sort_by_key(|(rect, _)| (rect.y, rect.x));
+
+    let mut row = 0;
+    let mut row_bottom = None;
+    let mut items: Vec<_> = items
+        .into_iter()
+        .map(|(rect, item)| {
+            let bottom = rect.y + rect.height;
+            row_bottom = match row_bottom {
+                Some(row_bottom) if rect.y < row_bottom => Some(bottom.max(row_bottom)),
+                Some(_) => {
+                    row += 1;
+                    Some(bottom)
+                }
+                None => Some(bottom),
+            };
+            ((row, rect.x, rect.y), item)
+        })
+        .collect();
+
+    items.sort_by_key(|(key, _)| *key);
+    items.into_iter().map(|(_, item)| item).collect()
+}
@@ -0,0 +1,91 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::convert::Infallible;
+use std::str::FromStr;
+
+/// An output name pattern where `*` matches any sequence and `?` one character.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputPattern {
+    pattern: Vec<char>,
+}
+
+impl OutputPattern {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.chars().collect(),
+        }
+    }
+
+    pub fn matches(&self, output_name: &str) -> bool {
+        let name: Vec<_> = output_name.chars().collect();
+        matches_rec(&self.pattern, &name)
+    }
+}
+
+fn matches_rec(pattern: &[char], name: &[char]) -> bool {
+    match pattern.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|skip| matches_rec(rest, &name[skip..])),
+        Some((&expected, rest)) => match name.split_first() {
+            Some((&actual, name_rest)) if expected == '?' || expected == actual => {
+                matches_rec(rest, name_rest)
+            }
+            _ => false,
+        },
+    }
+}
+
+impl FromStr for OutputPattern {
+    type Err = Infallible;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal_pattern_matches_only_same_name() {
+        let pattern = OutputPattern::new("DP-1");
+
+        assert!(pattern.matches("DP-1"));
+        assert!(!pattern.matches("DP-10"));
+        assert!(!pattern.matches("HDMI-A-1"));
+    }
+
+    #[test]
+    fn star_matches_any_sequence() {
+        let pattern = OutputPattern::new("HEADLESS-*");
+
+        assert!(pattern.matches("HEADLESS-"));
+        assert!(pattern.matches("HEADLESS-12"));
+        assert!(!pattern.matches("DP-1"));
+    }
+
+    #[test]
+    fn question_mark_matches_single_character() {
+        let pattern = OutputPattern::new("DP-?");
+
+        assert!(pattern.matches("DP-2"));
+        assert!(!pattern.matches("DP-"));
+        assert!(!pattern.matches("DP-12"));
+    }
+
+    #[test]
+    fn star_alone_matches_everything() {
+        let pattern = OutputPattern::new("*");
+
+        assert!(pattern.matches(""));
+        assert!(pattern.matches("eDP-1"));
+    }
+}
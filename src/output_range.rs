@@ -0,0 +1,68 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::number_range::NumberRange;
+use crate::output_pattern::OutputPattern;
+use crate::parse_error::ParseError;
+
+/// `OUTPUT=START..=END`, e.g. `DP-1=1..=99`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputRange {
+    pattern: OutputPattern,
+    range: NumberRange,
+}
+
+impl OutputRange {
+    pub fn new(pattern: OutputPattern, range: NumberRange) -> Self {
+        Self { pattern, range }
+    }
+
+    pub fn matches(&self, output_name: &str) -> bool {
+        self.pattern.matches(output_name)
+    }
+
+    pub fn range(&self) -> NumberRange {
+        self.range
+    }
+}
+
+impl FromStr for OutputRange {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (pattern, range) = value
+            .split_once('=')
+            .ok_or_else(|| ParseError::InvalidOutputRange(value.to_owned()))?;
+
+        Ok(Self::new(
+            OutputPattern::new(pattern.trim()),
+            range.parse()?,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_output_and_range() {
+        let output_range: OutputRange = "HDMI-A-1 = 101..=199".parse().unwrap();
+
+        assert!(output_range.matches("HDMI-A-1"));
+        assert_eq!(output_range.range(), NumberRange::new(101, 199));
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        assert!("DP-1 1..=99".parse::<OutputRange>().is_err());
+    }
+}
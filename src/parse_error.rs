@@ -0,0 +1,30 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum ParseError {
+    #[error("Unknown direction '{0}', expected one of left, right, up or down")]
+    UnknownDirection(String),
+    #[error("Invalid workspace number range '{0}', expected START..=END with 1 <= START <= END")]
+    InvalidNumberRange(String),
+    #[error("Invalid output range '{0}', expected OUTPUT=START..=END")]
+    InvalidOutputRange(String),
+    #[error("Invalid output columns '{0}', expected OUTPUT=COLUMNS with COLUMNS >= 1")]
+    InvalidOutputColumns(String),
+    #[error("Invalid project '{0}', expected NAME=START..=END")]
+    InvalidProject(String),
+    #[error("Unknown conflict policy '{0}', expected one of fail or skip")]
+    UnknownConflictPolicy(String),
+    #[error("Invalid window criteria: {0}")]
+    InvalidWindowCriteria(#[from] regex::Error),
+    #[error("Invalid mark criteria: {0}")]
+    InvalidMarkCriteria(regex::Error),
+}
@@ -0,0 +1,76 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::number_range::NumberRange;
+use crate::parse_error::ParseError;
+
+/// `NAME=START..=END`, e.g. `frontend=100..=199`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Project {
+    name: String,
+    range: NumberRange,
+}
+
+impl Project {
+    pub fn new(name: impl Into<String>, range: NumberRange) -> Self {
+        Self {
+            name: name.into(),
+            range,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn range(&self) -> NumberRange {
+        self.range
+    }
+}
+
+impl FromStr for Project {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError::InvalidProject(value.to_owned());
+
+        let (name, range) = value.split_once('=').ok_or_else(invalid)?;
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(invalid());
+        }
+
+        Ok(Self::new(name, range.parse()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_name_and_range() {
+        let project: Project = "frontend = 100..=199".parse().unwrap();
+
+        assert_eq!(project.name(), "frontend");
+        assert_eq!(project.range(), NumberRange::new(100, 199));
+    }
+
+    #[test]
+    fn rejects_missing_separator() {
+        assert!("frontend 100..=199".parse::<Project>().is_err());
+    }
+
+    #[test]
+    fn rejects_missing_name() {
+        assert!("=100..=199".parse::<Project>().is_err());
+    }
+}
@@ -0,0 +1,56 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::env;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+use crate::application_error::Error;
+
+/// Stored in `$XDG_STATE_HOME/sway-workspace-extras/project`.
+pub fn read_active_project() -> Result<Option<String>, Error> {
+    let Some(path) = state_file() else {
+        return Ok(None);
+    };
+
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content.trim().to_owned()).filter(|name| !name.is_empty())),
+        Err(error) if error.kind() == ErrorKind::NotFound => Ok(None),
+        Err(error) => Err(Error::ProjectState(error)),
+    }
+}
+
+pub fn write_active_project(name: Option<&str>) -> Result<(), Error> {
+    let path = state_file().ok_or(Error::MissingStateDirectory())?;
+
+    match name {
+        Some(name) => {
+            if let Some(directory) = path.parent() {
+                fs::create_dir_all(directory)?;
+            }
+            fs::write(path, format!("{name}\n"))?;
+        }
+        None => match fs::remove_file(path) {
+            Err(error) if error.kind() != ErrorKind::NotFound => return Err(error.into()),
+            _ => {}
+        },
+    }
+
+    Ok(())
+}
+
+fn state_file() -> Option<PathBuf> {
+    let state_home = env::var_os("XDG_STATE_HOME")
+        .map(PathBuf::from)
+        .filter(|path| path.is_absolute())
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".local/state")))?;
+
+    Some(state_home.join("sway-workspace-extras").join("project"))
+}
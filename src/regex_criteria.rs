@@ -0,0 +1,27 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub(crate) struct RegexCriteria {
+    regex: Regex,
+}
+
+impl RegexCriteria {
+    pub(crate) fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Ok(Self {
+            regex: Regex::new(pattern)?,
+        })
+    }
+
+    pub(crate) fn matches(&self, value: &str) -> bool {
+        self.regex.is_match(value)
+    }
+}
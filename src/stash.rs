@@ -0,0 +1,83 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::node_traits::SwayNode;
+
+const STASH_MARK_PREFIX: &str = "_stash:";
+
+/// Includes the container id, because Sway moves a mark that is already
+/// in use to the newly marked container.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StashMark {
+    pub origin: i32,
+    pub con_id: i64,
+}
+
+impl StashMark {
+    pub fn new(origin: i32, con_id: i64) -> Self {
+        Self { origin, con_id }
+    }
+
+    pub fn parse(mark: &str) -> Option<Self> {
+        let (origin, con_id) = mark.strip_prefix(STASH_MARK_PREFIX)?.split_once(':')?;
+        Some(Self {
+            origin: origin.parse().ok()?,
+            con_id: con_id.parse().ok()?,
+        })
+    }
+}
+
+impl Display for StashMark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{STASH_MARK_PREFIX}{}:{}", self.origin, self.con_id)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScratchpadContainer {
+    pub con_id: i64,
+    pub stash_mark: Option<StashMark>,
+}
+
+impl ScratchpadContainer {
+    pub(crate) fn of_node<Node: SwayNode>(node: &Node) -> Self {
+        Self {
+            con_id: node.get_id(),
+            stash_mark: node.get_marks().iter().find_map(|m| StashMark::parse(m)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_mark() {
+        let mark = StashMark::new(3, 42);
+
+        assert_eq!(mark.to_string(), "_stash:3:42");
+    }
+
+    #[test]
+    fn parses_formatted_mark() {
+        let mark = StashMark::parse("_stash:3:42");
+
+        assert_eq!(mark, Some(StashMark::new(3, 42)));
+    }
+
+    #[test]
+    fn ignores_other_marks() {
+        assert_eq!(StashMark::parse("stash:3:42"), None);
+        assert_eq!(StashMark::parse("_stash:3"), None);
+        assert_eq!(StashMark::parse("_stash:three:42"), None);
+    }
+}
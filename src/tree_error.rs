@@ -19,4 +19,6 @@ pub enum TreeError {
     MissingOutputName(i64),
     #[error("Could not find a workspace which has focus")]
     NoFocusedWorkspace(),
+    #[error("The focused output {0} is excluded")]
+    FocusOnExcludedOutput(String),
 }
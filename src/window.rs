@@ -0,0 +1,36 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use crate::node_traits::SwayNode;
+
+/// A tiling or floating container without children.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Window<'a> {
+    pub id: i64,
+    pub app_id: Option<&'a str>,
+    pub class: Option<&'a str>,
+    pub title: Option<&'a str>,
+    pub is_focused: bool,
+}
+
+impl<'a> Window<'a> {
+    pub(crate) fn of_node<Node: SwayNode>(node: &'a Node) -> Self {
+        Self {
+            id: node.get_id(),
+            app_id: node.get_app_id(),
+            class: node.get_window_class(),
+            title: node.get_name().as_deref(),
+            is_focused: node.is_focused(),
+        }
+    }
+}
+
+pub(crate) fn is_window<Node: SwayNode>(node: &Node) -> bool {
+    !node.is_workspace() && node.get_nodes().is_empty() && node.get_floating_nodes().is_empty()
+}
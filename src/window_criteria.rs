@@ -0,0 +1,95 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use std::str::FromStr;
+
+use crate::{ParseError, regex_criteria::RegexCriteria, window::Window};
+
+/// Matches if the app_id, the class or the title of a window matches.
+#[derive(Debug, Clone)]
+pub struct WindowCriteria {
+    criteria: RegexCriteria,
+}
+
+impl WindowCriteria {
+    pub fn matches(&self, window: &Window) -> bool {
+        [window.app_id, window.class, window.title]
+            .into_iter()
+            .flatten()
+            .any(|value| self.criteria.matches(value))
+    }
+}
+
+impl FromStr for WindowCriteria {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self {
+            criteria: RegexCriteria::new(s)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_app_id() {
+        let criteria: WindowCriteria = "^firefox$".parse().unwrap();
+
+        assert!(criteria.matches(&window(Some("firefox"), None, Some("Mozilla Firefox"))));
+        assert!(!criteria.matches(&window(Some("firefox-esr"), None, None)));
+    }
+
+    #[test]
+    fn matches_class() {
+        let criteria: WindowCriteria = "Slack".parse().unwrap();
+
+        assert!(criteria.matches(&window(None, Some("Slack"), None)));
+    }
+
+    #[test]
+    fn matches_title() {
+        let criteria: WindowCriteria = "(?i)inbox".parse().unwrap();
+
+        assert!(criteria.matches(&window(Some("thunderbird"), None, Some("Inbox - Mail"))));
+    }
+
+    #[test]
+    fn window_without_properties_never_matches() {
+        let criteria: WindowCriteria = ".*".parse().unwrap();
+
+        assert!(!criteria.matches(&window(None, None, None)));
+    }
+
+    #[test]
+    fn invalid_regex() {
+        let criteria = "firefox(".parse::<WindowCriteria>();
+
+        assert!(matches!(
+            criteria,
+            Err(ParseError::InvalidWindowCriteria(_))
+        ));
+    }
+
+    fn window<'a>(
+        app_id: Option<&'a str>,
+        class: Option<&'a str>,
+        title: Option<&'a str>,
+    ) -> Window<'a> {
+        Window {
+            id: 1,
+            app_id,
+            class,
+            title,
+            is_focused: false,
+        }
+    }
+}
@@ -7,13 +7,12 @@
  * received a copy of the license along with this program.
  */
 
-use std::{
-    cmp::{max, min},
-    vec,
+use crate::{
+    ConflictPolicy, Direction, Grid, MarkCriteria, NumberRange, OutputColumns, OutputRange,
+    StashMark, WindowCriteria, WorkflowError, WorkspaceAssignments, Workspaces,
+    workspace::Workspace,
 };
 
-use crate::{Workspaces, workspace::Workspace};
-
 #[derive(Debug, PartialEq, Eq)]
 pub enum Action {
     MoveFocus {
@@ -26,25 +25,127 @@ pub enum Action {
         workspace_name: String,
         new_workspace_name: String,
     },
+    MoveWorkspaceToOutput {
+        output_name: String,
+    },
+    FocusContainer {
+        con_id: i64,
+    },
+    MoveContainerById {
+        con_id: i64,
+        workspace_num: i32,
+    },
+    FocusParent,
+    MarkContainer {
+        con_id: i64,
+        mark: String,
+    },
+    UnmarkContainer {
+        con_id: i64,
+        mark: String,
+    },
+    MoveContainerToScratchpad {
+        con_id: i64,
+    },
+    ShowScratchpad {
+        con_id: i64,
+    },
+    DisableFloating {
+        con_id: i64,
+    },
+    FocusOutput {
+        output_name: String,
+    },
 }
 
 pub struct Workflow<W: Workspace> {
     workspaces: Workspaces<W>,
+    output_ranges: Vec<OutputRange>,
+    assignments: WorkspaceAssignments,
+    conflict_policy: Option<ConflictPolicy>,
+    container_level: usize,
+    follow: bool,
+    project_range: Option<NumberRange>,
+    grid_columns: Vec<OutputColumns>,
+    output_names: Vec<String>,
 }
 
 impl<W: Workspace> Workflow<W> {
     pub fn new(workspaces: Workspaces<W>) -> Self {
-        Self { workspaces }
+        let output_names = workspaces
+            .output_names()
+            .into_iter()
+            .map(str::to_owned)
+            .chain(workspaces.excluded_outputs().iter().cloned())
+            .collect();
+
+        Self {
+            workspaces,
+            output_ranges: vec![],
+            assignments: WorkspaceAssignments::default(),
+            conflict_policy: None,
+            container_level: 0,
+            follow: true,
+            project_range: None,
+            grid_columns: vec![],
+            output_names,
+        }
+    }
+
+    pub fn with_output_ranges(mut self, output_ranges: Vec<OutputRange>) -> Self {
+        self.output_ranges = output_ranges;
+        self
+    }
+
+    pub fn with_workspace_assignments(mut self, assignments: WorkspaceAssignments) -> Self {
+        self.assignments = assignments;
+        self
+    }
+
+    /// Without a policy `next` shifts the workspaces of other outputs, unless
+    /// ranges are configured. Then the default is to skip them.
+    pub fn with_conflict_policy(mut self, conflict_policy: Option<ConflictPolicy>) -> Self {
+        self.conflict_policy = conflict_policy;
+        self
     }
 
-    pub fn move_focus_to_next(&self) -> Vec<Action> {
+    /// Moves the ancestor `level` levels above the focused container,
+    /// at most the top-level container.
+    pub fn with_container_level(mut self, container_level: usize) -> Self {
+        self.container_level = container_level;
+        self
+    }
+
+    /// Without following, Sway keeps the emptied workspace, so a trailing
+    /// workspace is created even for its last container.
+    pub fn with_follow(mut self, follow: bool) -> Self {
+        self.follow = follow;
+        self
+    }
+
+    /// Workspaces outside of the project still count as taken.
+    pub fn with_project_range(mut self, project_range: Option<NumberRange>) -> Self {
+        if let Some(range) = project_range {
+            self.workspaces = self.workspaces.with_target_range(range);
+        }
+        self.project_range = project_range;
+        self
+    }
+
+    /// The grid starts with the first number of the output range, or 1.
+    pub fn with_grid_columns(mut self, grid_columns: Vec<OutputColumns>) -> Self {
+        self.grid_columns = grid_columns;
+        self
+    }
+
+    pub fn move_focus_to_next(&self) -> Result<Vec<Action>, WorkflowError> {
         let extend_output = |last_workspace: &W| last_workspace.contains_windows();
 
-        let next_workspace = self.find_next_workspace(extend_output);
+        let next_workspace = self.find_next_workspace(extend_output)?;
 
         if let Some((next_workspace_number, needs_shift)) = next_workspace {
             let mut actions = if needs_shift {
-                self.shift_successors()
+                self.shift_successors_of(next_workspace_number - 1)
             } else {
                 vec![]
             };
@@ -53,42 +154,42 @@ impl<W: Workspace> Workflow<W> {
                 workspace_num: next_workspace_number,
             });
 
-            actions
+            Ok(actions)
         } else {
-            vec![]
+            Ok(vec![])
         }
     }
 
-    pub fn move_container_to_next(&self) -> Vec<Action> {
+    pub fn move_container_to_next(&self) -> Result<Vec<Action>, WorkflowError> {
         if self.focused_workspace_is_empty() {
-            return vec![];
+            return Ok(vec![]);
         }
 
-        let extend_output = |last_workspace: &W| last_workspace.contains_not_focused_container();
+        let extend_output =
+            |last_workspace: &W| !self.follow || self.contains_other_than_moved(last_workspace);
 
-        let next_workspace = self.find_next_workspace(extend_output);
+        let next_workspace = self.find_next_workspace(extend_output)?;
 
         if let Some((next_workspace_number, needs_shift)) = next_workspace {
             let mut actions = if needs_shift {
-                self.shift_successors()
+                self.shift_successors_of(next_workspace_number - 1)
             } else {
                 vec![]
             };
 
+            actions.extend(self.select_moved_container());
             actions.push(Action::MoveContainer {
                 workspace_num: next_workspace_number,
             });
-            actions.push(Action::MoveFocus {
-                workspace_num: next_workspace_number,
-            });
+            actions.extend(self.follow_moved_container(next_workspace_number));
 
-            actions
+            Ok(actions)
         } else {
-            vec![]
+            Ok(vec![])
         }
     }
 
-    fn find_next_workspace<F>(&self, extend_output: F) -> Option<(i32, bool)>
+    fn find_next_workspace<F>(&self, extend_output: F) -> Result<Option<(i32, bool)>, WorkflowError>
     where
         F: Fn(&W) -> bool,
     {
@@ -98,7 +199,7 @@ impl<W: Workspace> Workflow<W> {
             .find(|w| self.current_output() == w.output_name());
 
         if let Some(next_on_output) = successor_on_same_output {
-            self.handle_more_successor_workspaces_on_output(&next_on_output)
+            Ok(self.handle_more_successor_workspaces_on_output(&next_on_output))
         } else {
             self.handle_no_more_successor_workspaces_on_output(extend_output)
         }
@@ -108,25 +209,14 @@ impl<W: Workspace> Workflow<W> {
         &self,
         next_on_output: &W,
     ) -> Option<(i32, bool)> {
-        let expected_successor_number = self.focused_workspace_number() + 1;
-
-        let next_missing_workspace = self
-            .workspaces
-            .successors_of_focused()
-            .zip(expected_successor_number..)
-            .find_map(|(w, expected_num)| {
-                if expected_num < w.workspace_number() {
-                    Some(expected_num)
-                } else {
-                    None
-                }
-            });
-
         let next_existing_num = next_on_output.workspace_number();
-        let next_workspace = min(
-            next_missing_workspace.unwrap_or(next_existing_num),
-            next_existing_num,
-        );
+
+        let next_workspace = (self.focused_workspace_number() + 1..next_existing_num)
+            .find(|num| {
+                self.is_free_for_output(*num, self.current_output())
+                    && self.is_in_range_of_output(*num, self.current_output())
+            })
+            .unwrap_or(next_existing_num);
 
         Some((next_workspace, false))
     }
@@ -134,125 +224,1086 @@ impl<W: Workspace> Workflow<W> {
     fn handle_no_more_successor_workspaces_on_output<F>(
         &self,
         extend_output: F,
-    ) -> Option<(i32, bool)>
+    ) -> Result<Option<(i32, bool)>, WorkflowError>
     where
         F: Fn(&W) -> bool,
     {
         if !extend_output(self.workspaces.focused_workspace()) {
-            return None;
+            return Ok(None);
         };
 
-        let expected_successor_number = self.focused_workspace_number() + 1;
+        self.new_workspace_after(self.focused_workspace_number(), self.current_output())
+    }
 
-        let needs_shift = self
-            .workspaces
-            .successor_of_focused()
-            .map(|w| w.workspace_number() == expected_successor_number)
-            .unwrap_or(false);
+    fn new_workspace_after(
+        &self,
+        workspace_number: i32,
+        output_name: &str,
+    ) -> Result<Option<(i32, bool)>, WorkflowError> {
+        let Some(expected_successor_number) = (self
+            .first_number_after(workspace_number, output_name)..)
+            .find(|num| !self.is_reserved_for_other_output(*num, output_name))
+        else {
+            return Ok(None);
+        };
+
+        let Some(other) = self.workspaces.find_by_number(expected_successor_number) else {
+            let in_range = self
+                .range_of_output(output_name)
+                .is_none_or(|r| r.contains(expected_successor_number));
+            return Ok(in_range.then_some((expected_successor_number, false)));
+        };
 
-        Some((expected_successor_number, needs_shift))
+        match self.successor_conflict_policy() {
+            None => Ok(Some((expected_successor_number, true))),
+            Some(ConflictPolicy::Fail) => Err(Self::number_taken_error(&other)),
+            Some(ConflictPolicy::Skip) => Ok(self
+                .next_free_number_on_output(workspace_number, output_name)
+                .map(|num| (num, false))),
+        }
     }
 
-    pub fn move_focus_to_prev(&self) -> Vec<Action> {
-        match self.find_previous_workspace() {
-            Some(workspace_num) => vec![Action::MoveFocus { workspace_num }],
-            None => vec![],
+    fn successor_conflict_policy(&self) -> Option<ConflictPolicy> {
+        if !self.has_ranges() {
+            self.conflict_policy
+        } else {
+            self.conflict_policy.or(Some(ConflictPolicy::Skip))
         }
     }
 
-    pub fn move_container_to_prev(&self) -> Vec<Action> {
+    fn next_free_number_on_output(&self, workspace_number: i32, output_name: &str) -> Option<i32> {
+        let range = self.range_of_output(output_name);
+
+        (self.first_number_after(workspace_number, output_name)..)
+            .find(|num| self.is_free_for_output(*num, output_name))
+            .filter(|num| range.is_none_or(|r| r.contains(*num)))
+    }
+
+    fn first_number_after(&self, workspace_number: i32, output_name: &str) -> i32 {
+        self.range_of_output(output_name)
+            .map_or(workspace_number + 1, |r| {
+                r.start().max(workspace_number + 1)
+            })
+    }
+
+    pub fn move_focus_to_prev(&self) -> Result<Vec<Action>, WorkflowError> {
+        match self.find_previous_workspace()? {
+            Some(workspace_num) => Ok(vec![Action::MoveFocus { workspace_num }]),
+            None => Ok(vec![]),
+        }
+    }
+
+    pub fn move_container_to_prev(&self) -> Result<Vec<Action>, WorkflowError> {
         if self.focused_workspace_is_empty() {
-            return vec![];
+            return Ok(vec![]);
+        }
+
+        match self.find_previous_workspace()? {
+            Some(workspace_num) => {
+                let mut actions = self.select_moved_container();
+                actions.push(Action::MoveContainer { workspace_num });
+                actions.extend(self.follow_moved_container(workspace_num));
+                Ok(actions)
+            }
+            None => Ok(vec![]),
+        }
+    }
+
+    /// The workspace of the marked container takes the place of the focused one.
+    pub fn move_marked_to_next(&self, mark: &str) -> Result<Vec<Action>, WorkflowError> {
+        let Some((source, con_id)) = self.find_marked_container(mark) else {
+            return Ok(vec![]);
+        };
+        let view = self.refocused(source);
+
+        let extend_output = |last_workspace: &W| {
+            (!self.follow && last_workspace.is_focused())
+                || last_workspace.contains_container_other_than(con_id)
+        };
+
+        let Some((workspace_num, needs_shift)) = view.find_next_workspace(extend_output)? else {
+            return Ok(vec![]);
+        };
+
+        let mut actions = if needs_shift {
+            view.shift_successors_of(workspace_num - 1)
+        } else {
+            vec![]
+        };
+        actions.push(Action::MoveContainerById {
+            con_id,
+            workspace_num,
+        });
+        actions.extend(self.follow_moved_container(workspace_num));
+
+        Ok(actions)
+    }
+
+    pub fn move_marked_to_prev(&self, mark: &str) -> Result<Vec<Action>, WorkflowError> {
+        let Some((source, con_id)) = self.find_marked_container(mark) else {
+            return Ok(vec![]);
+        };
+
+        match self.refocused(source).find_previous_workspace()? {
+            Some(workspace_num) => Ok(self.move_marked_container_to(con_id, workspace_num)),
+            None => Ok(vec![]),
         }
+    }
 
-        match self.find_previous_workspace() {
-            Some(workspace_num) => vec![
-                Action::MoveContainer { workspace_num },
-                Action::MoveFocus { workspace_num },
-            ],
-            None => vec![],
+    pub fn move_marked_to_next_global(&self, mark: &str) -> Result<Vec<Action>, WorkflowError> {
+        let Some((source, con_id)) = self.find_marked_container(mark) else {
+            return Ok(vec![]);
+        };
+
+        match self.refocused(source).first_workspace_of_next_output() {
+            Some(workspace_num) => Ok(self.move_marked_container_to(con_id, workspace_num)),
+            None => self.move_marked_to_next(mark),
         }
     }
 
-    fn find_previous_workspace(&self) -> Option<i32> {
+    pub fn move_marked_to_prev_global(&self, mark: &str) -> Result<Vec<Action>, WorkflowError> {
+        let Some((source, con_id)) = self.find_marked_container(mark) else {
+            return Ok(vec![]);
+        };
+
+        match self.refocused(source).last_workspace_of_prev_output() {
+            Some(workspace_num) => Ok(self.move_marked_container_to(con_id, workspace_num)),
+            None => self.move_marked_to_prev(mark),
+        }
+    }
+
+    fn move_marked_container_to(&self, con_id: i64, workspace_num: i32) -> Vec<Action> {
+        let mut actions = vec![Action::MoveContainerById {
+            con_id,
+            workspace_num,
+        }];
+        actions.extend(self.follow_moved_container(workspace_num));
+        actions
+    }
+
+    fn follow_moved_container(&self, workspace_num: i32) -> Option<Action> {
+        self.follow.then_some(Action::MoveFocus { workspace_num })
+    }
+
+    pub fn move_marked_containers_here(&self, criteria: &MarkCriteria) -> Vec<Action> {
+        let focused_number = self.focused_workspace_number();
+
+        self.workspaces
+            .all()
+            .filter(|w| w.workspace_number() != focused_number)
+            .flat_map(|w| w.containers_with_mark(|mark| criteria.matches(mark)))
+            .map(|con_id| Action::MoveContainerById {
+                con_id,
+                workspace_num: focused_number,
+            })
+            .collect()
+    }
+
+    fn find_marked_container(&self, mark: &str) -> Option<(W, i64)> {
+        self.workspaces.all().find_map(|w| {
+            w.containers_with_mark(|m| m == mark)
+                .first()
+                .map(|con_id| (w, *con_id))
+        })
+    }
+
+    fn refocused(&self, workspace: W) -> Self {
+        Self {
+            workspaces: self.workspaces.refocused(workspace),
+            output_ranges: self.output_ranges.clone(),
+            assignments: self.assignments.clone(),
+            conflict_policy: self.conflict_policy,
+            container_level: self.container_level,
+            follow: self.follow,
+            project_range: self.project_range,
+            grid_columns: self.grid_columns.clone(),
+            output_names: self.output_names.clone(),
+        }
+    }
+
+    fn moved_container(&self) -> Option<(i64, usize)> {
+        let path = self.workspaces.focused_workspace().focused_container_path();
+        let level = self.container_level.min(path.len().checked_sub(1)?);
+        Some((path[path.len() - 1 - level], level))
+    }
+
+    fn select_moved_container(&self) -> Vec<Action> {
+        let level = self.moved_container().map_or(0, |(_, level)| level);
+        (0..level).map(|_| Action::FocusParent).collect()
+    }
+
+    fn contains_other_than_moved(&self, workspace: &W) -> bool {
+        if self.container_level == 0 {
+            return workspace.contains_not_focused_container();
+        }
+
+        self.moved_container()
+            .is_some_and(|(con_id, _)| workspace.contains_container_other_than(con_id))
+    }
+
+    fn find_previous_workspace(&self) -> Result<Option<i32>, WorkflowError> {
         let prev_workspace_on_output = self
             .workspaces
             .predecessors_of_focused()
             .find(|w| self.current_output() == w.output_name());
 
         if let Some(prev_workspace) = prev_workspace_on_output {
-            self.handle_more_predecessor_workspaces_on_output(prev_workspace)
+            Ok(self.handle_more_predecessor_workspaces_on_output(prev_workspace))
         } else {
             self.handle_no_more_predecessor_workspaces_on_output()
         }
     }
 
     fn handle_more_predecessor_workspaces_on_output(&self, prev_workspace: W) -> Option<i32> {
-        let expected_predecessor_number = self.focused_workspace_number() - 1;
+        let prev_workspace_number = prev_workspace.workspace_number();
+
+        let prev_workspace_number = (prev_workspace_number + 1..self.focused_workspace_number())
+            .rev()
+            .find(|num| {
+                self.is_free_for_output(*num, self.current_output())
+                    && self.is_in_range_of_output(*num, self.current_output())
+            })
+            .unwrap_or(prev_workspace_number);
+
+        Some(prev_workspace_number)
+    }
+
+    fn handle_no_more_predecessor_workspaces_on_output(
+        &self,
+    ) -> Result<Option<i32>, WorkflowError> {
+        let current_output = self.current_output();
+
+        let lowest_number = self
+            .range_of_output(current_output)
+            .map(|r| r.start())
+            .unwrap_or(1);
+
+        let mut candidates = (lowest_number..self.focused_workspace_number()).rev();
+
+        if self.conflict_policy == Some(ConflictPolicy::Skip) {
+            return Ok(candidates.find(|num| self.is_free_for_output(*num, current_output)));
+        }
+
+        let candidate = candidates.find(|num| {
+            self.workspaces.contains_number(*num)
+                || !self.is_reserved_for_other_output(*num, current_output)
+        });
+
+        match candidate.map(|num| (num, self.workspaces.find_by_number(num))) {
+            Some((num, None)) => Ok(Some(num)),
+            Some((_, Some(other))) if self.conflict_policy == Some(ConflictPolicy::Fail) => {
+                Err(Self::number_taken_error(&other))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// At the last workspace of the output, continues on the next output.
+    pub fn move_focus_to_next_global(&self) -> Result<Vec<Action>, WorkflowError> {
+        match self.first_workspace_of_next_output() {
+            Some(workspace_num) => Ok(vec![Action::MoveFocus { workspace_num }]),
+            None => self.move_focus_to_next(),
+        }
+    }
+
+    pub fn move_focus_to_prev_global(&self) -> Result<Vec<Action>, WorkflowError> {
+        match self.last_workspace_of_prev_output() {
+            Some(workspace_num) => Ok(vec![Action::MoveFocus { workspace_num }]),
+            None => self.move_focus_to_prev(),
+        }
+    }
+
+    pub fn move_container_to_next_global(&self) -> Result<Vec<Action>, WorkflowError> {
+        if self.focused_workspace_is_empty() {
+            return Ok(vec![]);
+        }
+
+        match self.first_workspace_of_next_output() {
+            Some(workspace_num) => Ok(self.move_container_to(workspace_num)),
+            None => self.move_container_to_next(),
+        }
+    }
+
+    pub fn move_container_to_prev_global(&self) -> Result<Vec<Action>, WorkflowError> {
+        if self.focused_workspace_is_empty() {
+            return Ok(vec![]);
+        }
+
+        match self.last_workspace_of_prev_output() {
+            Some(workspace_num) => Ok(self.move_container_to(workspace_num)),
+            None => self.move_container_to_prev(),
+        }
+    }
+
+    fn move_container_to(&self, workspace_num: i32) -> Vec<Action> {
+        let mut actions = self.select_moved_container();
+        actions.push(Action::MoveContainer { workspace_num });
+        actions.extend(self.follow_moved_container(workspace_num));
+        actions
+    }
 
-        let last_missing_workspace = self
+    fn first_workspace_of_next_output(&self) -> Option<i32> {
+        let current_output = self.current_output();
+        let is_last_on_output = self
+            .workspaces
+            .successors_of_focused()
+            .all(|w| w.output_name() != current_output);
+        if !is_last_on_output {
+            return None;
+        }
+
+        self.workspaces
+            .workspaces_on_output(self.neighbouring_output(1)?)
+            .next()
+            .map(|w| w.workspace_number())
+    }
+
+    fn last_workspace_of_prev_output(&self) -> Option<i32> {
+        let current_output = self.current_output();
+        let is_first_on_output = self
             .workspaces
             .predecessors_of_focused()
-            .zip((0..=expected_predecessor_number).rev())
-            .find_map(|(w, expected_num)| {
-                if expected_num > w.workspace_number() {
-                    Some(expected_num)
+            .all(|w| w.output_name() != current_output);
+        if !is_first_on_output {
+            return None;
+        }
+
+        self.workspaces
+            .workspaces_on_output(self.neighbouring_output(-1)?)
+            .last()
+            .map(|w| w.workspace_number())
+    }
+
+    fn neighbouring_output(&self, offset: isize) -> Option<&str> {
+        let outputs = self.workspaces.output_names_by_position();
+        let index = outputs.iter().position(|o| *o == self.current_output())?;
+        outputs.get(index.checked_add_signed(offset)?).copied()
+    }
+
+    pub fn move_focus_to_next_existing(&self, wrap: bool) -> Vec<Action> {
+        self.focus_next_on_output_matching(wrap, |_| true)
+    }
+
+    pub fn move_focus_to_prev_existing(&self, wrap: bool) -> Vec<Action> {
+        self.focus_prev_on_output_matching(wrap, |_| true)
+    }
+
+    pub fn move_focus_to_next_occupied(&self, wrap: bool) -> Vec<Action> {
+        self.focus_next_on_output_matching(wrap, W::contains_windows)
+    }
+
+    pub fn move_focus_to_prev_occupied(&self, wrap: bool) -> Vec<Action> {
+        self.focus_prev_on_output_matching(wrap, W::contains_windows)
+    }
+
+    fn focus_next_on_output_matching<F>(&self, wrap: bool, predicate: F) -> Vec<Action>
+    where
+        F: Fn(&W) -> bool,
+    {
+        let on_output: Vec<_> = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .filter(predicate)
+            .collect();
+        let focused_number = self.focused_workspace_number();
+
+        let next = on_output
+            .iter()
+            .find(|w| w.workspace_number() > focused_number)
+            .or(on_output.first().filter(|_| wrap))
+            .copied();
+
+        Self::focus_other_than_focused(next, focused_number)
+    }
+
+    fn focus_prev_on_output_matching<F>(&self, wrap: bool, predicate: F) -> Vec<Action>
+    where
+        F: Fn(&W) -> bool,
+    {
+        let on_output: Vec<_> = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .filter(predicate)
+            .collect();
+        let focused_number = self.focused_workspace_number();
+
+        let prev = on_output
+            .iter()
+            .rev()
+            .find(|w| w.workspace_number() < focused_number)
+            .or(on_output.last().filter(|_| wrap))
+            .copied();
+
+        Self::focus_other_than_focused(prev, focused_number)
+    }
+
+    fn focus_other_than_focused(workspace: Option<W>, focused_number: i32) -> Vec<Action> {
+        workspace
+            .map(|w| w.workspace_number())
+            .filter(|num| *num != focused_number)
+            .map(|workspace_num| vec![Action::MoveFocus { workspace_num }])
+            .unwrap_or_default()
+    }
+
+    /// Free numbers between the workspaces of the current output compete with
+    /// a new trailing workspace. On equal distance the later number wins.
+    pub fn move_focus_to_next_empty(&self) -> Result<Vec<Action>, WorkflowError> {
+        if self.focused_workspace_is_empty() {
+            return Ok(vec![]);
+        }
+
+        let current_output = self.current_output();
+        let focused_number = self.focused_workspace_number();
+        let numbers: Vec<_> = self
+            .workspaces
+            .workspaces_on_output(current_output)
+            .map(|w| w.workspace_number())
+            .collect();
+        let first_number = numbers.first().copied().unwrap_or(focused_number);
+        let last_number = numbers.last().copied().unwrap_or(focused_number);
+        let distance = |num: i32| ((num - focused_number).abs(), num < focused_number);
+
+        let nearest_gap = (first_number + 1..last_number)
+            .filter(|num| self.is_free_for_output(*num, current_output))
+            .min_by_key(|num| distance(*num));
+
+        let trailing = match nearest_gap {
+            Some(gap) if distance(gap) < distance(last_number + 1) => None,
+            _ => self.new_workspace_after(last_number, current_output)?,
+        };
+
+        match (nearest_gap, trailing) {
+            (Some(gap), Some((new_number, _))) if distance(gap) < distance(new_number) => {
+                Ok(vec![Action::MoveFocus { workspace_num: gap }])
+            }
+            (_, Some((new_number, needs_shift))) => {
+                let mut actions = if needs_shift {
+                    self.shift_successors_of(new_number - 1)
                 } else {
-                    None
-                }
+                    vec![]
+                };
+                actions.push(Action::MoveFocus {
+                    workspace_num: new_number,
+                });
+                Ok(actions)
+            }
+            (Some(gap), None) => Ok(vec![Action::MoveFocus { workspace_num: gap }]),
+            (None, None) => Ok(vec![]),
+        }
+    }
+
+    /// Successors on the current output come first, then its predecessors,
+    /// then the other outputs in their order.
+    pub fn move_focus_to_next_urgent(&self) -> Vec<Action> {
+        let current_output = self.current_output();
+        let focused_number = self.focused_workspace_number();
+
+        let (on_output, mut on_other_outputs): (Vec<_>, Vec<_>) = self
+            .workspaces
+            .all()
+            .filter(|w| w.workspace_number() != focused_number && w.contains_urgent_window())
+            .partition(|w| w.output_name() == current_output);
+
+        let outputs = self.workspaces.output_names_by_position();
+        on_other_outputs.sort_by_key(|w| {
+            let output_index = outputs.iter().position(|o| *o == w.output_name());
+            (output_index, w.workspace_number())
+        });
+
+        let (successors, predecessors): (Vec<_>, Vec<_>) = on_output
+            .into_iter()
+            .partition(|w| w.workspace_number() > focused_number);
+
+        successors
+            .into_iter()
+            .chain(predecessors)
+            .chain(on_other_outputs)
+            .next()
+            .map(|w| {
+                vec![Action::MoveFocus {
+                    workspace_num: w.workspace_number(),
+                }]
+            })
+            .unwrap_or_default()
+    }
+
+    /// If the focused window matches, the next match is focused instead.
+    pub fn focus_matching_window(&self, criteria: &WindowCriteria) -> Vec<Action> {
+        let matches: Vec<_> = self
+            .workspaces
+            .all()
+            .flat_map(|w| {
+                w.windows()
+                    .into_iter()
+                    .filter(|window| criteria.matches(window))
+                    .map(|window| (window.id, window.is_focused))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        let next_match = match matches.iter().position(|(_, is_focused)| *is_focused) {
+            Some(focused_index) => {
+                (matches.len() > 1).then(|| &matches[(focused_index + 1) % matches.len()])
+            }
+            None => matches.first(),
+        };
+
+        next_match
+            .map(|(con_id, _)| vec![Action::FocusContainer { con_id: *con_id }])
+            .unwrap_or_default()
+    }
+
+    pub fn move_matching_window_here(&self, criteria: &WindowCriteria) -> Vec<Action> {
+        let focused_number = self.focused_workspace_number();
+
+        let matching_window = self
+            .workspaces
+            .all()
+            .filter(|w| w.workspace_number() != focused_number)
+            .find_map(|w| {
+                w.windows()
+                    .into_iter()
+                    .find(|window| criteria.matches(window))
+                    .map(|window| window.id)
             });
 
-        let prev_workspace_number = prev_workspace.workspace_number();
-        let prev_workspace_number = max(
-            last_missing_workspace.unwrap_or(prev_workspace_number),
-            prev_workspace_number,
-        );
+        matching_window
+            .map(|con_id| {
+                vec![
+                    Action::MoveContainerById {
+                        con_id,
+                        workspace_num: focused_number,
+                    },
+                    Action::FocusContainer { con_id },
+                ]
+            })
+            .unwrap_or_default()
+    }
 
-        Some(prev_workspace_number)
+    /// Workspaces emptied this way are removed by Sway and the gaps they
+    /// leave are closed.
+    pub fn gather_matching_windows(&self, criteria: &WindowCriteria) -> Vec<Action> {
+        let focused_number = self.focused_workspace_number();
+        let mut actions = vec![];
+        let mut emptied = vec![];
+
+        for workspace in self
+            .workspaces
+            .all()
+            .filter(|w| w.workspace_number() != focused_number)
+        {
+            let windows = workspace.windows();
+            let matching: Vec<_> = windows
+                .iter()
+                .filter(|window| criteria.matches(window))
+                .collect();
+
+            actions.extend(matching.iter().map(|window| Action::MoveContainerById {
+                con_id: window.id,
+                workspace_num: focused_number,
+            }));
+
+            if !matching.is_empty() && matching.len() == windows.len() && !workspace.is_visible() {
+                emptied.push(workspace.workspace_number());
+            }
+        }
+
+        actions.extend(self.close_gaps_of_removed_workspaces(&emptied));
+        actions
+    }
+
+    fn close_gaps_of_removed_workspaces(&self, removed: &[i32]) -> Vec<Action> {
+        let mut actions = vec![];
+
+        for output_name in self.workspaces.output_names() {
+            let on_output: Vec<_> = self.workspaces.workspaces_on_output(output_name).collect();
+            let mut next_number = None;
+            let mut previous_number = None;
+
+            for workspace in on_output {
+                let number = workspace.workspace_number();
+                if previous_number != Some(number - 1) {
+                    next_number = Some(number);
+                }
+                previous_number = Some(number);
+
+                if removed.contains(&number) {
+                    continue;
+                }
+
+                let new_number = next_number.unwrap_or(number);
+                if new_number != number {
+                    actions.push(Self::rename_workspace(&workspace, new_number));
+                }
+                next_number = Some(new_number + 1);
+            }
+        }
+
+        actions
+    }
+
+    /// Prefers workspaces of the current output, else uses the range start.
+    pub fn move_focus_into_range(&self, range: NumberRange) -> Vec<Action> {
+        if range.contains(self.focused_workspace_number()) {
+            return vec![];
+        }
+
+        let in_range = |w: &W| range.contains(w.workspace_number());
+        let workspace_num = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .find(in_range)
+            .or_else(|| self.workspaces.all().find(in_range))
+            .map_or(range.start(), |w| w.workspace_number());
+
+        vec![Action::MoveFocus { workspace_num }]
     }
 
-    fn handle_no_more_predecessor_workspaces_on_output(&self) -> Option<i32> {
-        let expected_predecessor_number = self.focused_workspace_number() - 1;
+    /// The group is the position of the focused workspace in its output range.
+    pub fn focus_next_group(&self) -> Vec<Action> {
+        self.focused_group()
+            .map(|group| self.focus_group(group + 1))
+            .unwrap_or_default()
+    }
 
-        let predecessor_on_different_output = self.workspaces.predecessor_of_focused();
+    pub fn focus_prev_group(&self) -> Vec<Action> {
+        self.focused_group()
+            .map(|group| self.focus_group(group - 1))
+            .unwrap_or_default()
+    }
 
-        let prev_workspace_number = max(
-            expected_predecessor_number,
-            predecessor_on_different_output
-                .map(|w| w.workspace_number() + 1)
-                .unwrap_or(1),
+    /// Shows workspace `start + group - 1` on every output with a range.
+    pub fn focus_group(&self, group: i32) -> Vec<Action> {
+        let current_output = self.current_output();
+        let mut actions = vec![];
+
+        for output_name in self.workspaces.output_names() {
+            if output_name == current_output {
+                continue;
+            }
+            if let Some(workspace_num) = self.group_workspace_to_show(output_name, group) {
+                actions.push(Action::FocusOutput {
+                    output_name: output_name.to_owned(),
+                });
+                actions.push(Action::MoveFocus { workspace_num });
+            }
+        }
+
+        if !actions.is_empty() {
+            actions.push(Action::FocusOutput {
+                output_name: current_output.to_owned(),
+            });
+        }
+        actions.extend(
+            self.group_workspace_to_show(current_output, group)
+                .map(|workspace_num| Action::MoveFocus { workspace_num }),
         );
 
-        if prev_workspace_number < self.focused_workspace_number() {
-            Some(prev_workspace_number)
+        actions
+    }
+
+    fn focused_group(&self) -> Option<i32> {
+        let range = self.range_of_output(self.current_output())?;
+        let focused_number = self.focused_workspace_number();
+        range
+            .contains(focused_number)
+            .then(|| focused_number - range.start() + 1)
+    }
+
+    fn group_workspace_to_show(&self, output_name: &str, group: i32) -> Option<i32> {
+        if group < 1 {
+            return None;
+        }
+
+        let range = self.range_of_output(output_name)?;
+        let workspace_num = range.start().checked_add(group - 1)?;
+        if !range.contains(workspace_num) {
+            return None;
+        }
+
+        match self.workspaces.find_by_number(workspace_num) {
+            Some(w) if w.output_name() != output_name || w.is_visible() => None,
+            Some(_) => Some(workspace_num),
+            None => (!self.is_reserved_for_other_output(workspace_num, output_name))
+                .then_some(workspace_num),
+        }
+    }
+
+    pub fn move_focus_in_grid(&self, direction: Direction) -> Result<Vec<Action>, WorkflowError> {
+        let Some(workspace_num) = self.grid_neighbour(direction)? else {
+            return Ok(vec![]);
+        };
+
+        let exists_on_output = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .any(|w| w.workspace_number() == workspace_num);
+
+        Ok(if exists_on_output {
+            vec![Action::MoveFocus { workspace_num }]
         } else {
-            None
+            vec![]
+        })
+    }
+
+    /// Doesn't create a workspace with a number used or assigned elsewhere.
+    pub fn move_container_in_grid(
+        &self,
+        direction: Direction,
+    ) -> Result<Vec<Action>, WorkflowError> {
+        if self.focused_workspace_is_empty() {
+            return Ok(vec![]);
         }
+
+        let Some(workspace_num) = self.grid_neighbour(direction)? else {
+            return Ok(vec![]);
+        };
+
+        let current_output = self.current_output();
+        let usable = match self.workspaces.find_by_number(workspace_num) {
+            Some(w) => w.output_name() == current_output,
+            None => !self.is_reserved_for_other_output(workspace_num, current_output),
+        };
+        if !usable {
+            return Ok(vec![]);
+        }
+
+        let mut actions = self.select_moved_container();
+        actions.push(Action::MoveContainer { workspace_num });
+        actions.extend(self.follow_moved_container(workspace_num));
+        Ok(actions)
+    }
+
+    fn grid_neighbour(&self, direction: Direction) -> Result<Option<i32>, WorkflowError> {
+        let current_output = self.current_output();
+        let columns = self
+            .grid_columns
+            .iter()
+            .find(|c| c.matches(current_output))
+            .map(OutputColumns::columns)
+            .ok_or_else(|| WorkflowError::NoGridColumns(current_output.into()))?;
+
+        let range = self.range_of_output(current_output);
+        let grid = Grid::new(range.map_or(1, |r| r.start()), columns);
+
+        Ok(grid
+            .neighbour(self.focused_workspace_number(), direction)
+            .filter(|num| range.is_none_or(|r| r.contains(*num))))
+    }
+
+    pub fn stash(&self) -> Vec<Action> {
+        let focused_path = self.workspaces.focused_workspace().focused_container_path();
+        let Some(con_id) = focused_path.last().copied() else {
+            return vec![];
+        };
+
+        let mark = StashMark::new(self.focused_workspace_number(), con_id);
+        vec![
+            Action::MarkContainer {
+                con_id,
+                mark: mark.to_string(),
+            },
+            Action::MoveContainerToScratchpad { con_id },
+        ]
     }
 
-    pub fn shift_successors(&self) -> Vec<Action> {
-        let expected_number_of_successor = self.focused_workspace_number() + 1;
+    /// Prefers containers stashed from the focused workspace, then other
+    /// stashed containers.
+    pub fn unstash_next(&self) -> Result<Vec<Action>, WorkflowError> {
+        let focused_number = self.focused_workspace_number();
+        let scratchpad = self.workspaces.scratchpad();
 
+        let Some(container) = scratchpad
+            .iter()
+            .find(|c| c.stash_mark.is_some_and(|m| m.origin == focused_number))
+            .or_else(|| scratchpad.iter().find(|c| c.stash_mark.is_some()))
+            .or_else(|| scratchpad.first())
+        else {
+            return Ok(vec![]);
+        };
+
+        let con_id = container.con_id;
+        let (new_numbers, mut actions) =
+            self.make_room_after_focused(1, self.successor_conflict_policy())?;
+        actions.push(Action::ShowScratchpad { con_id });
+        actions.push(Action::DisableFloating { con_id });
+        actions.extend(
+            new_numbers
+                .into_iter()
+                .map(|workspace_num| Action::MoveContainerById {
+                    con_id,
+                    workspace_num,
+                }),
+        );
+        actions.extend(container.stash_mark.map(|mark| Action::UnmarkContainer {
+            con_id,
+            mark: mark.to_string(),
+        }));
+        actions.push(Action::FocusContainer { con_id });
+
+        Ok(actions)
+    }
+
+    pub fn explode(&self) -> Result<Vec<Action>, WorkflowError> {
+        let containers = self
+            .workspaces
+            .focused_workspace()
+            .not_focused_top_level_containers();
+
+        let (new_numbers, mut actions) =
+            self.make_room_after_focused(containers.len(), self.successor_conflict_policy())?;
+        actions.extend(
+            containers
+                .into_iter()
+                .zip(new_numbers)
+                .map(|(con_id, workspace_num)| Action::MoveContainerById {
+                    con_id,
+                    workspace_num,
+                }),
+        );
+
+        Ok(actions)
+    }
+
+    /// With `compact` the gap left by the removed workspace is closed.
+    pub fn merge_into_next(&self, compact: bool) -> Vec<Action> {
+        let focused_number = self.focused_workspace_number();
+        let next = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .find(|w| w.workspace_number() > focused_number);
+
+        self.merge_into(next, compact)
+    }
+
+    pub fn merge_into_prev(&self, compact: bool) -> Vec<Action> {
+        let focused_number = self.focused_workspace_number();
+        let prev = self
+            .workspaces
+            .workspaces_on_output(self.current_output())
+            .filter(|w| w.workspace_number() < focused_number)
+            .last();
+
+        self.merge_into(prev, compact)
+    }
+
+    fn merge_into(&self, target: Option<W>, compact: bool) -> Vec<Action> {
+        let Some(target) = target else {
+            return vec![];
+        };
+        if self.focused_workspace_is_empty() {
+            return vec![];
+        }
+
+        let workspace_num = target.workspace_number();
         let mut actions: Vec<_> = self
             .workspaces
-            .successors_of_focused()
-            .zip(expected_number_of_successor..)
-            .take_while(|(workspace, expected_num)| *expected_num == workspace.workspace_number())
-            .map(|(workspace, _)| Action::RenameWorkspace {
-                workspace_name: workspace.workspace_name().into(),
-                new_workspace_name: format!(
-                    "{}{}",
-                    workspace.workspace_number() + 1,
-                    workspace.workspace_name_without_number()
-                ),
+            .focused_workspace()
+            .top_level_containers()
+            .into_iter()
+            .map(|con_id| Action::MoveContainerById {
+                con_id,
+                workspace_num,
             })
             .collect();
+        actions.push(Action::MoveFocus { workspace_num });
 
-        actions.reverse();
+        if compact {
+            actions
+                .extend(self.close_gaps_of_removed_workspaces(&[self.focused_workspace_number()]));
+        }
 
         actions
     }
 
+    pub fn move_container_to_output(
+        &self,
+        direction: Direction,
+    ) -> Result<Vec<Action>, WorkflowError> {
+        if self.focused_workspace_is_empty() {
+            return Ok(vec![]);
+        }
+
+        let Some(workspace_on_target_output) =
+            self.find_workspace_on_output_in_direction(direction)
+        else {
+            return Ok(vec![]);
+        };
+        let target_output = workspace_on_target_output.output_name();
+
+        let Some(last_workspace) = self.workspaces.workspaces_on_output(target_output).last()
+        else {
+            return Ok(vec![]);
+        };
+
+        let last_workspace_number = last_workspace.workspace_number();
+
+        if !last_workspace.contains_windows() {
+            return Ok(vec![
+                Action::MoveContainer {
+                    workspace_num: last_workspace_number,
+                },
+                Action::MoveFocus {
+                    workspace_num: last_workspace_number,
+                },
+            ]);
+        }
+
+        let Some((trailing_workspace_number, needs_shift)) =
+            self.new_workspace_after(last_workspace_number, target_output)?
+        else {
+            return Ok(vec![]);
+        };
+
+        let mut actions = if needs_shift {
+            self.shift_successors_of(trailing_workspace_number - 1)
+        } else {
+            vec![]
+        };
+        actions.push(Action::MoveContainer {
+            workspace_num: trailing_workspace_number,
+        });
+        actions.push(Action::MoveFocus {
+            workspace_num: trailing_workspace_number,
+        });
+        actions.push(Action::MoveWorkspaceToOutput {
+            output_name: target_output.into(),
+        });
+
+        Ok(actions)
+    }
+
+    fn find_workspace_on_output_in_direction(&self, direction: Direction) -> Option<W> {
+        let current_rect = self.workspaces.focused_workspace().output_rect();
+
+        self.workspaces
+            .all()
+            .filter(|w| w.output_rect().is_in_direction_of(&current_rect, direction))
+            .min_by_key(|w| w.output_rect().squared_center_distance(&current_rect))
+    }
+
+    pub fn shift_successors(&self) -> Result<Vec<Action>, WorkflowError> {
+        if !self.has_ranges() {
+            Ok(self.shift_successors_of(self.focused_workspace_number()))
+        } else {
+            self.shift_successors_on_output(ConflictPolicy::Fail)
+        }
+    }
+
+    fn shift_successors_of(&self, workspace_number: i32) -> Vec<Action> {
+        let mut highest_needed_number = workspace_number + 1;
+        let mut chain = vec![];
+
+        for workspace in self.workspaces.successors_of(workspace_number) {
+            if workspace.workspace_number() > highest_needed_number {
+                break;
+            }
+
+            let mut new_number = (workspace.workspace_number() + 1).max(
+                chain
+                    .last()
+                    .map_or(i32::MIN, |(_, previous_number)| previous_number + 1),
+            );
+            while self.is_reserved_for_other_output(new_number, workspace.output_name()) {
+                new_number += 1;
+            }
+
+            chain.push((workspace, new_number));
+            highest_needed_number = new_number;
+        }
+
+        chain
+            .iter()
+            .rev()
+            .map(|(w, new_number)| Self::rename_workspace(w, *new_number))
+            .collect()
+    }
+
+    /// Like `shift_successors` but only renames workspaces of the current output.
+    pub fn shift_successors_on_output(
+        &self,
+        policy: ConflictPolicy,
+    ) -> Result<Vec<Action>, WorkflowError> {
+        let (_, renames) = self.make_room_after_focused(1, Some(policy))?;
+        Ok(renames)
+    }
+
+    /// Takes `count` numbers after the focused workspace and renames the
+    /// workspaces in their way behind them, keeping their order. Without a
+    /// policy the workspaces of other outputs are renamed as well.
+    fn make_room_after_focused(
+        &self,
+        count: usize,
+        policy: Option<ConflictPolicy>,
+    ) -> Result<(Vec<i32>, Vec<Action>), WorkflowError> {
+        let current_output = self.current_output();
+
+        let mut last_number = self.focused_workspace_number();
+        let mut new_numbers = vec![];
+        for _ in 0..count {
+            last_number = self.free_slot_after(last_number, current_output, policy)?;
+            new_numbers.push(last_number);
+        }
+
+        let mut renames = vec![];
+        let successors = self
+            .workspaces
+            .successors_of_focused()
+            .filter(|w| policy.is_none() || w.output_name() == current_output);
+        for workspace in successors {
+            if workspace.workspace_number() > last_number {
+                break;
+            }
+            last_number = self.free_slot_after(last_number, workspace.output_name(), policy)?;
+            renames.push(Self::rename_workspace(&workspace, last_number));
+        }
+        renames.reverse();
+
+        Ok((new_numbers, renames))
+    }
+
+    fn free_slot_after(
+        &self,
+        workspace_number: i32,
+        output_name: &str,
+        policy: Option<ConflictPolicy>,
+    ) -> Result<i32, WorkflowError> {
+        let candidates = (self.first_number_after(workspace_number, output_name)..)
+            .take_while(|num| self.is_in_range_of_output(*num, output_name))
+            .filter(|num| !self.is_reserved_for_other_output(*num, output_name));
+
+        for num in candidates {
+            match (policy, self.workspaces.find_by_number(num)) {
+                (Some(policy), Some(other)) if other.output_name() != output_name => {
+                    if policy == ConflictPolicy::Fail {
+                        return Err(Self::number_taken_error(&other));
+                    }
+                }
+                _ => return Ok(num),
+            }
+        }
+
+        Err(WorkflowError::NoFreeNumberInRange(output_name.into()))
+    }
+
+    fn number_taken_error(workspace: &W) -> WorkflowError {
+        WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: workspace.workspace_number(),
+            output_name: workspace.output_name().into(),
+        }
+    }
+
+    fn rename_workspace(workspace: &W, new_number: i32) -> Action {
+        Action::RenameWorkspace {
+            workspace_name: workspace.workspace_name().into(),
+            new_workspace_name: format!(
+                "{}{}",
+                new_number,
+                workspace.workspace_name_without_number()
+            ),
+        }
+    }
+
     fn focused_workspace_number(&self) -> i32 {
         self.workspaces.focused_workspace().workspace_number()
     }
@@ -264,4 +1315,41 @@ impl<W: Workspace> Workflow<W> {
     fn current_output(&self) -> &str {
         self.workspaces.focused_workspace().output_name()
     }
+
+    fn is_free_for_output(&self, workspace_number: i32, output_name: &str) -> bool {
+        !self.workspaces.contains_number(workspace_number)
+            && !self.is_reserved_for_other_output(workspace_number, output_name)
+    }
+
+    fn is_reserved_for_other_output(&self, workspace_number: i32, output_name: &str) -> bool {
+        self.workspaces.is_reserved(workspace_number)
+            || self
+                .assignments
+                .assigned_output(workspace_number, &self.output_names)
+                .is_some_and(|assigned| assigned != output_name)
+    }
+
+    fn range_of_output(&self, output_name: &str) -> Option<NumberRange> {
+        let output_range = self
+            .output_ranges
+            .iter()
+            .find(|output_range| output_range.matches(output_name))
+            .map(OutputRange::range);
+
+        match (output_range, self.project_range) {
+            (Some(output_range), Some(project_range)) => {
+                Some(output_range.intersection(&project_range))
+            }
+            (output_range, project_range) => output_range.or(project_range),
+        }
+    }
+
+    fn is_in_range_of_output(&self, workspace_number: i32, output_name: &str) -> bool {
+        self.range_of_output(output_name)
+            .is_none_or(|r| r.contains(workspace_number))
+    }
+
+    fn has_ranges(&self) -> bool {
+        !self.output_ranges.is_empty() || self.project_range.is_some()
+    }
 }
@@ -0,0 +1,23 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+use thiserror::Error as ThisError;
+
+#[derive(Debug, PartialEq, Eq, ThisError)]
+pub enum WorkflowError {
+    #[error("Workspace number {workspace_number} is already used on output {output_name}")]
+    NumberTakenByOtherOutput {
+        workspace_number: i32,
+        output_name: String,
+    },
+    #[error("There is no free workspace number left in the range of output {0}")]
+    NoFreeNumberInRange(String),
+    #[error("There is no number of grid columns configured for output {0}")]
+    NoGridColumns(String),
+}
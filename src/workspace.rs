@@ -7,46 +7,138 @@
  * received a copy of the license along with this program.
  */
 
+use crate::emptiness_rules::EmptinessRules;
 use crate::find_all_nodes::FindAllNodes;
+use crate::geometry::Rect;
 use crate::is_scratchpad::IsScratchpad;
 use crate::node_traits::SwayNode;
+use crate::number_range::NumberRange;
+use crate::output_order::sort_by_position;
+use crate::output_pattern::OutputPattern;
+use crate::stash::ScratchpadContainer;
 use crate::tree_error::TreeError;
+use crate::window::{Window, is_window};
 
 pub trait Workspace: Copy {
     fn workspace_name(&self) -> &str;
     fn workspace_name_without_number(&self) -> &str;
     fn workspace_number(&self) -> i32;
     fn output_name(&self) -> &str;
+    fn output_rect(&self) -> Rect;
     fn contains_windows(&self) -> bool;
     fn is_focused(&self) -> bool;
+    fn is_visible(&self) -> bool;
+    fn contains_urgent_window(&self) -> bool;
     fn contains_not_focused_container(&self) -> bool;
+    fn contains_container_other_than(&self, con_id: i64) -> bool;
+    fn focused_container_path(&self) -> Vec<i64>;
+    fn top_level_containers(&self) -> Vec<i64>;
+    fn containers_with_mark<F>(&self, predicate: F) -> Vec<i64>
+    where
+        F: Fn(&str) -> bool;
+    fn not_focused_top_level_containers(&self) -> Vec<i64>;
+    fn windows(&self) -> Vec<Window<'_>>;
 }
 
 pub struct Workspaces<W: Workspace> {
     workspaces: Vec<W>,
     focused_workspace: W,
+    scratchpad: Vec<ScratchpadContainer>,
+    reserved_numbers: Vec<i32>,
+    excluded_outputs: Vec<String>,
+    target_range: Option<NumberRange>,
 }
 
 impl<W: Workspace> Workspaces<W> {
-    pub fn new(mut workspaces: Vec<W>) -> Result<Self, TreeError> {
+    pub fn new(workspaces: Vec<W>) -> Result<Self, TreeError> {
+        Self::new_with_fallback(workspaces, None)
+    }
+
+    /// Falls back to the visible workspace of the output if no workspace
+    /// has the focus.
+    pub fn new_with_fallback(
+        mut workspaces: Vec<W>,
+        focused_output: Option<&str>,
+    ) -> Result<Self, TreeError> {
         workspaces.sort_by_key(W::workspace_number);
 
-        let focused_workspace = Self::find_focused_workspace(&workspaces)?;
+        let focused_workspace = Self::find_focused_workspace(&workspaces, focused_output)?;
 
         Ok(Self {
             workspaces,
             focused_workspace,
+            scratchpad: vec![],
+            reserved_numbers: vec![],
+            excluded_outputs: vec![],
+            target_range: None,
         })
     }
 
-    fn find_focused_workspace(workspaces: &[W]) -> Result<W, TreeError> {
+    fn find_focused_workspace(
+        workspaces: &[W],
+        focused_output: Option<&str>,
+    ) -> Result<W, TreeError> {
+        let visible_on_focused_output =
+            |w: &&W| Some(w.output_name()) == focused_output && w.is_visible();
+
         workspaces
             .iter()
             .find(|w| w.is_focused())
+            .or_else(|| workspaces.iter().find(visible_on_focused_output))
             .copied()
             .ok_or(TreeError::NoFocusedWorkspace())
     }
 
+    pub fn refocused(&self, workspace: W) -> Self {
+        Self {
+            workspaces: self.workspaces.clone(),
+            focused_workspace: workspace,
+            scratchpad: self.scratchpad.clone(),
+            reserved_numbers: self.reserved_numbers.clone(),
+            excluded_outputs: self.excluded_outputs.clone(),
+            target_range: self.target_range,
+        }
+    }
+
+    pub fn with_scratchpad(mut self, scratchpad: Vec<ScratchpadContainer>) -> Self {
+        self.scratchpad = scratchpad;
+        self
+    }
+
+    pub fn scratchpad(&self) -> &[ScratchpadContainer] {
+        &self.scratchpad
+    }
+
+    /// Lookups by number still see workspaces outside of the range.
+    pub fn with_target_range(mut self, target_range: NumberRange) -> Self {
+        self.target_range = Some(target_range);
+        self
+    }
+
+    fn is_target(&self, workspace: &W) -> bool {
+        let number = workspace.workspace_number();
+        self.target_range.is_none_or(|r| r.contains(number))
+            || number == self.focused_workspace.workspace_number()
+    }
+
+    pub fn with_reserved_numbers(mut self, reserved_numbers: Vec<i32>) -> Self {
+        self.reserved_numbers = reserved_numbers;
+        self
+    }
+
+    pub fn is_reserved(&self, workspace_number: i32) -> bool {
+        self.reserved_numbers.contains(&workspace_number)
+    }
+
+    pub fn with_excluded_outputs(mut self, excluded_outputs: Vec<String>) -> Self {
+        self.excluded_outputs = excluded_outputs;
+        self
+    }
+
+    pub fn excluded_outputs(&self) -> &[String] {
+        &self.excluded_outputs
+    }
+
     pub fn focused_workspace(&self) -> &W {
         &self.focused_workspace
     }
@@ -56,10 +148,63 @@ impl<W: Workspace> Workspaces<W> {
     }
 
     pub fn successors_of_focused(&self) -> impl Iterator<Item = W> + '_ {
-        let focused_num = self.focused_workspace().workspace_number();
+        self.successors_of(self.focused_workspace().workspace_number())
+            .filter(|w| self.is_target(w))
+    }
+
+    pub fn successors_of(&self, workspace_number: i32) -> impl Iterator<Item = W> + '_ {
         self.workspaces
             .iter()
-            .filter(move |w| w.workspace_number() > focused_num)
+            .filter(move |w| w.workspace_number() > workspace_number)
+            .copied()
+    }
+
+    pub fn workspaces_on_output<'b>(
+        &'b self,
+        output_name: &'b str,
+    ) -> impl Iterator<Item = W> + 'b {
+        self.all().filter(move |w| w.output_name() == output_name)
+    }
+
+    pub fn contains_number(&self, workspace_number: i32) -> bool {
+        self.find_by_number(workspace_number).is_some()
+    }
+
+    pub fn find_by_number(&self, workspace_number: i32) -> Option<W> {
+        self.workspaces
+            .iter()
+            .find(|w| w.workspace_number() == workspace_number)
+            .copied()
+    }
+
+    pub fn output_names(&self) -> Vec<&str> {
+        let mut output_names: Vec<_> = self.workspaces.iter().map(W::output_name).collect();
+        output_names.sort_unstable();
+        output_names.dedup();
+        output_names
+    }
+
+    pub fn output_names_by_position(&self) -> Vec<&str> {
+        let outputs: Vec<_> = self
+            .output_names()
+            .into_iter()
+            .filter_map(|name| {
+                let workspace = self.workspaces.iter().find(|w| w.output_name() == name)?;
+                let rect = workspace.output_rect();
+                Some((name, rect))
+            })
+            .collect();
+
+        sort_by_position(outputs, |(_, rect)| *rect)
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    pub fn all(&self) -> impl DoubleEndedIterator<Item = W> + '_ {
+        self.workspaces
+            .iter()
+            .filter(|w| self.is_target(w))
             .copied()
     }
 
@@ -69,11 +214,9 @@ impl<W: Workspace> Workspaces<W> {
 
     pub fn predecessors_of_focused(&self) -> impl Iterator<Item = W> + '_ {
         let focused_num = self.focused_workspace().workspace_number();
-        self.workspaces
-            .iter()
+        self.all()
             .filter(move |w| w.workspace_number() < focused_num)
             .rev()
-            .copied()
     }
 }
 
@@ -81,32 +224,152 @@ impl<W: Workspace> Workspaces<W> {
 pub struct SwayWorkspace<'a, Node> {
     workspace_name: &'a str,
     output_name: &'a str,
+    output_rect: Rect,
+    rules: &'a EmptinessRules,
     workspace: Node,
     num: i32,
 }
 
+static DEFAULT_EMPTINESS_RULES: EmptinessRules = EmptinessRules::new();
+
 pub fn get_workspaces_of<'a, Node: SwayNode>(
     tree: &'a Node,
 ) -> Result<Workspaces<SwayWorkspace<'a, &'a Node>>, TreeError> {
+    get_workspaces_with_rules(tree, &DEFAULT_EMPTINESS_RULES)
+}
+
+pub fn get_workspaces_with_rules<'a, Node: SwayNode>(
+    tree: &'a Node,
+    rules: &'a EmptinessRules,
+) -> Result<Workspaces<SwayWorkspace<'a, &'a Node>>, TreeError> {
+    get_workspaces_excluding_outputs(tree, rules, &[])
+}
+
+/// Reserves the numbers of excluded outputs. Fails if one of them has
+/// the focus.
+pub fn get_workspaces_excluding_outputs<'a, Node: SwayNode>(
+    tree: &'a Node,
+    rules: &'a EmptinessRules,
+    excluded_outputs: &[OutputPattern],
+) -> Result<Workspaces<SwayWorkspace<'a, &'a Node>>, TreeError> {
+    let is_excluded = |output: &Node| {
+        output
+            .get_name()
+            .as_ref()
+            .is_some_and(|name| excluded_outputs.iter().any(|p| p.matches(name)))
+    };
+
     let output_to_workspaces = |output: &'a Node| {
         output
             .find_all_nodes_by(SwayNode::is_workspace)
             .into_iter()
             .filter(|w| !w.is_scratchpad_workspace())
-            .map(|w| SwayWorkspace::new_from_output_and_workspace_nodes(output, w))
+            .map(|w| SwayWorkspace::new_from_output_and_workspace_nodes(output, w, rules))
     };
 
-    tree.find_all_nodes_by(SwayNode::is_output)
+    let (excluded, included): (Vec<_>, Vec<_>) = tree
+        .find_all_nodes_by(SwayNode::is_output)
+        .into_iter()
+        .partition(|output| is_excluded(output));
+
+    if let Some(output) = excluded
+        .iter()
+        .find(|output| output.find_as_ref(|n| n.is_focused()).is_some())
+    {
+        let name = output.get_name().clone().unwrap_or_default();
+        return Err(TreeError::FocusOnExcludedOutput(name));
+    }
+
+    let excluded_names = excluded
+        .iter()
+        .filter_map(|output| output.get_name().clone())
+        .collect();
+    let reserved_numbers = excluded
+        .into_iter()
+        .flat_map(|output| output.find_all_nodes_by(SwayNode::is_workspace))
+        .filter_map(|w| w.get_num())
+        .collect();
+
+    sort_by_position(included, |output| output.get_rect())
         .into_iter()
         .flat_map(output_to_workspaces)
         .collect::<Result<Vec<_>, _>>()
-        .and_then(Workspaces::new)
+        .and_then(|workspaces| {
+            let focused_output = focused_output_name(tree, is_excluded);
+            Workspaces::new_with_fallback(workspaces, focused_output)
+        })
+        .map(|workspaces| {
+            workspaces
+                .with_scratchpad(scratchpad_containers(tree))
+                .with_reserved_numbers(reserved_numbers)
+                .with_excluded_outputs(excluded_names)
+        })
+}
+
+fn scratchpad_containers<Node: SwayNode>(tree: &Node) -> Vec<ScratchpadContainer> {
+    tree.find_as_ref(|n| n.is_scratchpad_workspace())
+        .map(|scratchpad| {
+            scratchpad
+                .get_nodes()
+                .iter()
+                .chain(scratchpad.get_floating_nodes().iter())
+                .map(ScratchpadContainer::of_node)
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn focused_output_name<Node, F>(tree: &Node, is_excluded: F) -> Option<&str>
+where
+    Node: SwayNode,
+    F: Fn(&Node) -> bool,
+{
+    tree.get_focus()
+        .iter()
+        .filter_map(|id| tree.get_nodes().iter().find(|n| n.get_id() == *id))
+        .find(|output| output.is_output() && !output.is_scratchpad_output() && !is_excluded(output))
+        .and_then(|output| output.get_name().as_deref())
 }
 
 impl<'a, Node: SwayNode> SwayWorkspace<'a, &'a Node> {
+    fn focus_chain(&self) -> Vec<&'a Node> {
+        let mut chain = vec![self.workspace];
+
+        while let Some(next) = chain.last().and_then(|node| {
+            if node.is_focused() {
+                return None;
+            }
+            let focused_id = node.get_focus().first()?;
+            node.get_nodes()
+                .iter()
+                .chain(node.get_floating_nodes().iter())
+                .find(|child| child.get_id() == *focused_id)
+        }) {
+            chain.push(next);
+        }
+
+        chain
+    }
+
+    fn top_level_nodes(&self) -> impl Iterator<Item = &'a Node> + use<'a, Node> {
+        self.workspace
+            .get_nodes()
+            .iter()
+            .chain(self.workspace.get_floating_nodes().iter())
+    }
+
+    fn focused_top_level_container(&self) -> Option<i64> {
+        if !self.is_focused() {
+            return None;
+        }
+
+        self.focus_chain().get(1).map(|node| node.get_id())
+    }
+
     fn new_from_output_and_workspace_nodes(
         output: &'a Node,
         workspace: &'a Node,
+        rules: &'a EmptinessRules,
     ) -> Result<Self, TreeError> {
         let workspace_name = workspace
             .get_name()
@@ -127,6 +390,8 @@ impl<'a, Node: SwayNode> SwayWorkspace<'a, &'a Node> {
         Ok(Self {
             workspace_name,
             output_name,
+            output_rect: output.get_rect(),
+            rules,
             num,
             workspace,
         })
@@ -153,19 +418,92 @@ impl<'a, Node: SwayNode> Workspace for SwayWorkspace<'a, &'a Node> {
         self.output_name
     }
 
+    fn output_rect(&self) -> Rect {
+        self.output_rect
+    }
+
     fn contains_windows(&self) -> bool {
-        !self.workspace.get_nodes().is_empty() || !self.workspace.get_floating_nodes().is_empty()
+        self.top_level_nodes().any(|node| !self.rules.ignores(node))
     }
 
     fn is_focused(&self) -> bool {
-        self.workspace.find_as_ref(|n| n.is_focused()).is_some()
+        self.focus_chain().iter().any(|n| n.is_focused())
+    }
+
+    fn is_visible(&self) -> bool {
+        self.workspace.is_visible()
+    }
+
+    fn contains_urgent_window(&self) -> bool {
+        self.workspace.find_as_ref(|n| n.is_urgent()).is_some()
     }
 
     fn contains_not_focused_container(&self) -> bool {
-        self.workspace
-            .get_nodes()
+        match self.focus_chain().as_slice() {
+            [_, .., focused] if focused.is_focused() => {
+                self.contains_container_other_than(focused.get_id())
+            }
+            _ => self.contains_windows(),
+        }
+    }
+
+    fn contains_container_other_than(&self, con_id: i64) -> bool {
+        let windows_of_container: Vec<_> = self
+            .workspace
+            .find_as_ref(|n| n.get_id() == con_id)
+            .map(|container| container.find_all_nodes_by(is_window))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|window| window.get_id())
+            .collect();
+
+        self.top_level_nodes()
+            .filter(|node| !self.rules.ignores(*node))
+            .flat_map(|node| node.find_all_nodes_by(is_window))
+            .any(|window| !windows_of_container.contains(&window.get_id()))
+    }
+
+    fn focused_container_path(&self) -> Vec<i64> {
+        if !self.is_focused() {
+            return vec![];
+        }
+
+        self.focus_chain()
             .iter()
-            .chain(self.workspace.get_floating_nodes().iter())
-            .any(|node| !node.is_focused())
+            .skip(1)
+            .map(|node| node.get_id())
+            .collect()
+    }
+
+    fn containers_with_mark<F>(&self, predicate: F) -> Vec<i64>
+    where
+        F: Fn(&str) -> bool,
+    {
+        self.workspace
+            .find_outermost_nodes_by(|n| {
+                !n.is_workspace() && n.get_marks().iter().any(|m| predicate(m))
+            })
+            .into_iter()
+            .map(|node| node.get_id())
+            .collect()
+    }
+
+    fn top_level_containers(&self) -> Vec<i64> {
+        self.top_level_nodes().map(|node| node.get_id()).collect()
+    }
+
+    fn not_focused_top_level_containers(&self) -> Vec<i64> {
+        self.top_level_nodes()
+            .map(|node| node.get_id())
+            .filter(|id| Some(*id) != self.focused_top_level_container())
+            .collect()
+    }
+
+    fn windows(&self) -> Vec<Window<'_>> {
+        self.workspace
+            .find_all_nodes_by(is_window)
+            .into_iter()
+            .map(Window::of_node)
+            .collect()
     }
 }
@@ -0,0 +1,229 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+/// The `workspace <num> output <outputs...>` assignments of a Sway config.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WorkspaceAssignments {
+    assignments: Vec<Assignment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Assignment {
+    workspace_number: i32,
+    outputs: Vec<String>,
+}
+
+impl WorkspaceAssignments {
+    pub fn parse(config: &str) -> Self {
+        let mut variables: Vec<(String, String)> = vec![];
+        let mut assignments = vec![];
+
+        for line in config.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+
+            let words = split_words(&substitute_variables(line, &variables));
+
+            match words.as_slice() {
+                [set, name, value @ ..] if set == "set" && name.starts_with('$') => {
+                    let value = substitute_variables(&value.join(" "), &variables);
+                    variables.push((name.clone(), value));
+                    // Longer names first, so $ws1 doesn't replace a prefix of $ws10
+                    variables.sort_by_key(|(name, _)| std::cmp::Reverse(name.len()));
+                }
+                [workspace, rest @ ..] if workspace == "workspace" => {
+                    if let Some(assignment) = parse_assignment(rest) {
+                        assignments.push(assignment);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Self { assignments }
+    }
+
+    /// Like Sway, the first assigned output that exists wins.
+    pub fn assigned_output<'a, S: AsRef<str>>(
+        &'a self,
+        workspace_number: i32,
+        existing_outputs: &[S],
+    ) -> Option<&'a str> {
+        self.assignments
+            .iter()
+            .filter(|a| a.workspace_number == workspace_number)
+            .flat_map(|a| a.outputs.iter())
+            .map(String::as_str)
+            .find(|output| existing_outputs.iter().any(|o| o.as_ref() == *output))
+    }
+}
+
+fn parse_assignment(words: &[String]) -> Option<Assignment> {
+    let words = match words {
+        [number, rest @ ..] if number == "number" => rest,
+        _ => words,
+    };
+
+    match words {
+        [name, output, outputs @ ..] if output == "output" && !outputs.is_empty() => {
+            Some(Assignment {
+                workspace_number: leading_number(name)?,
+                outputs: outputs.to_vec(),
+            })
+        }
+        _ => None,
+    }
+}
+
+fn leading_number(workspace_name: &str) -> Option<i32> {
+    let digits: String = workspace_name
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+fn substitute_variables(line: &str, variables: &[(String, String)]) -> String {
+    if line.starts_with("set ") {
+        return line.to_owned();
+    }
+
+    variables
+        .iter()
+        .fold(line.to_owned(), |line, (name, value)| {
+            line.replace(name, value)
+        })
+}
+
+fn split_words(line: &str) -> Vec<String> {
+    let mut words = vec![];
+    let mut current = String::new();
+    let mut quote = None;
+
+    for c in line.chars() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, c) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            _ => current.push(c),
+        }
+    }
+
+    if !current.is_empty() {
+        words.push(current);
+    }
+
+    words
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_config_has_no_assignments() {
+        let assignments = WorkspaceAssignments::parse("");
+
+        assert_eq!(assignments.assigned_output(1, &["DP-1"]), None);
+    }
+
+    #[test]
+    fn parses_numbered_workspace_assignment() {
+        let assignments = WorkspaceAssignments::parse("workspace 5 output HDMI-A-1");
+
+        assert_eq!(
+            assignments.assigned_output(5, &["DP-1", "HDMI-A-1"]),
+            Some("HDMI-A-1")
+        );
+        assert_eq!(assignments.assigned_output(6, &["DP-1", "HDMI-A-1"]), None);
+    }
+
+    #[test]
+    fn parses_quoted_workspace_names() {
+        let assignments = WorkspaceAssignments::parse("workspace \"3: mail\" output DP-1");
+
+        assert_eq!(assignments.assigned_output(3, &["DP-1"]), Some("DP-1"));
+    }
+
+    #[test]
+    fn first_existing_output_wins() {
+        let assignments = WorkspaceAssignments::parse("workspace 2 output DP-2 HDMI-A-1 DP-1");
+
+        assert_eq!(
+            assignments.assigned_output(2, &["DP-1", "HDMI-A-1"]),
+            Some("HDMI-A-1")
+        );
+    }
+
+    #[test]
+    fn assignment_to_missing_output_is_ignored() {
+        let assignments = WorkspaceAssignments::parse("workspace 2 output DP-2");
+
+        assert_eq!(assignments.assigned_output(2, &["DP-1"]), None);
+    }
+
+    #[test]
+    fn substitutes_variables() {
+        let config = "\
+            set $ws1 1\n\
+            set $ws10 10\n\
+            set $right HDMI-A-1\n\
+            workspace $ws1 output $right\n\
+            workspace $ws10 output $right\n";
+
+        let assignments = WorkspaceAssignments::parse(config);
+
+        assert_eq!(
+            assignments.assigned_output(1, &["HDMI-A-1"]),
+            Some("HDMI-A-1")
+        );
+        assert_eq!(
+            assignments.assigned_output(10, &["HDMI-A-1"]),
+            Some("HDMI-A-1")
+        );
+    }
+
+    #[test]
+    fn substitutes_variables_in_variable_definitions() {
+        let config = "\
+            set $main DP-1\n\
+            set $left $main\n\
+            workspace 1 output $left\n";
+
+        let assignments = WorkspaceAssignments::parse(config);
+
+        assert_eq!(assignments.assigned_output(1, &["DP-1"]), Some("DP-1"));
+    }
+
+    #[test]
+    fn ignores_comments_and_other_workspace_commands() {
+        let config = "\
+            # workspace 1 output DP-1\n\
+            workspace 2 gaps inner 4\n\
+            workspace_layout tabbed\n\
+            bindsym $mod+1 workspace number 1\n";
+
+        let assignments = WorkspaceAssignments::parse(config);
+
+        assert_eq!(assignments, WorkspaceAssignments::default());
+    }
+
+    #[test]
+    fn ignores_workspaces_without_number() {
+        let assignments = WorkspaceAssignments::parse("workspace mail output DP-1");
+
+        assert_eq!(assignments, WorkspaceAssignments::default());
+    }
+}
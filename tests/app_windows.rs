@@ -0,0 +1,148 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, WindowCriteria, Workflow, get_workspaces_of};
+
+#[test]
+fn goto_app_without_match() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_app_window("foot", "~");
+    });
+
+    let actions = when_goto_app(&tree, "firefox");
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn goto_app_focuses_window_by_app_id() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2
+                .workspace(2)
+                .add_app_window("foot", "~")
+                .add_app_window("firefox", "Start Page");
+        },
+    );
+
+    let actions = when_goto_app(&tree, "^firefox$");
+
+    assert_eq!(actions, &[Action::FocusContainer { con_id: 10 }]);
+}
+
+#[test]
+fn goto_app_matches_class_and_title() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_x11_window("Slack", "general");
+        output.workspace(3).add_app_window("thunderbird", "Inbox");
+    });
+
+    assert_eq!(
+        when_goto_app(&tree, "Slack"),
+        &[Action::FocusContainer { con_id: 8 }]
+    );
+    assert_eq!(
+        when_goto_app(&tree, "Inbox"),
+        &[Action::FocusContainer { con_id: 10 }]
+    );
+}
+
+#[test]
+fn goto_app_cycles_through_matches() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_app_window("foot", "a");
+        output.workspace(2).add_focused_app_window("foot", "b");
+        output.workspace(3).add_app_window("foot", "c");
+    });
+
+    let actions = when_goto_app(&tree, "foot");
+
+    assert_eq!(actions, &[Action::FocusContainer { con_id: 10 }]);
+}
+
+#[test]
+fn goto_app_wraps_around_to_first_match() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_app_window("foot", "a");
+        output.workspace(2).add_focused_app_window("foot", "b");
+    });
+
+    let actions = when_goto_app(&tree, "foot");
+
+    assert_eq!(actions, &[Action::FocusContainer { con_id: 6 }]);
+}
+
+#[test]
+fn goto_app_with_focused_window_as_only_match() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_app_window("foot", "a");
+        output.workspace(2).add_app_window("firefox", "b");
+    });
+
+    let actions = when_goto_app(&tree, "foot");
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn move_here_pulls_window_to_focused_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_app_window("firefox", "a");
+        output.workspace(2).add_focused_window();
+    });
+
+    let actions = when_move_here(&tree, "firefox");
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 6,
+                workspace_num: 2
+            },
+            Action::FocusContainer { con_id: 6 }
+        ]
+    );
+}
+
+#[test]
+fn move_here_ignores_windows_on_focused_workspace() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_app_window("firefox", "a");
+    });
+
+    let actions = when_move_here(&tree, "firefox");
+
+    assert_eq!(actions, &[]);
+}
+
+fn when_goto_app(tree: &Node, criteria: &str) -> Vec<Action> {
+    let workflow = get_workspaces_of(tree).map(Workflow::new).unwrap();
+    workflow.focus_matching_window(&parse_criteria(criteria))
+}
+
+fn when_move_here(tree: &Node, criteria: &str) -> Vec<Action> {
+    let workflow = get_workspaces_of(tree).map(Workflow::new).unwrap();
+    workflow.move_matching_window_here(&parse_criteria(criteria))
+}
+
+fn parse_criteria(criteria: &str) -> WindowCriteria {
+    criteria.parse().unwrap()
+}
@@ -0,0 +1,211 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, two_outputs};
+use sway_workspace_extras::{
+    Action, ConflictPolicy, Workflow, WorkflowError, Workspace, get_workspaces_of,
+};
+
+#[test]
+fn next_skips_all_numbers_of_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(4).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, None).move_focus_to_next();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 4 }]));
+}
+
+#[test]
+fn next_uses_gap_between_workspaces_of_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(5).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, None).move_focus_to_next();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn next_fails_if_trailing_number_is_taken() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Fail)).move_focus_to_next();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 2,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn next_skips_taken_trailing_numbers() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Skip)).move_focus_to_next();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 4 }]));
+}
+
+#[test]
+fn move_next_fails_if_trailing_number_is_taken() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window().add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Fail)).move_container_to_next();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 2,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn prev_uses_gap_below_workspaces_of_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+            output_1.workspace(5).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, None).move_focus_to_prev();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 2 }]));
+}
+
+#[test]
+fn prev_stops_in_front_of_other_output_by_default() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, None).move_focus_to_prev();
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn prev_fails_if_number_is_taken() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Fail)).move_focus_to_prev();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 2,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn prev_skips_taken_numbers() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Skip)).move_focus_to_prev();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 1 }]));
+}
+
+#[test]
+fn move_prev_without_free_number() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(1).add_window();
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_policy(&tree, Some(ConflictPolicy::Skip)).move_container_to_prev();
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+fn with_policy<'a>(
+    tree: &'a Node,
+    policy: Option<ConflictPolicy>,
+) -> Workflow<impl Workspace + 'a> {
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_conflict_policy(policy)
+}
@@ -0,0 +1,91 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output};
+use sway_workspace_extras::{Action, EmptinessRules, Workflow, get_workspaces_with_rules};
+
+#[test]
+fn sticky_window_makes_workspace_occupied_by_default() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused().add_sticky_floating_window();
+    });
+
+    let actions = when_move_focus_to_next(&tree, &EmptinessRules::default());
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn ignores_sticky_window() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused().add_sticky_floating_window();
+    });
+
+    let rules = EmptinessRules::default().with_ignore_sticky(true);
+    let actions = when_move_focus_to_next(&tree, &rules);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn sticky_window_does_not_hide_other_windows() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_sticky_floating_window();
+    });
+
+    let rules = EmptinessRules::default().with_ignore_sticky(true);
+    let actions = when_move_focus_to_next(&tree, &rules);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn ignores_windows_matching_criteria() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .focused()
+            .add_floating_app_window("mpv", "Picture-in-Picture");
+    });
+
+    let rules = EmptinessRules::default().with_ignored_windows(vec!["^mpv$".parse().unwrap()]);
+    let actions = when_move_focus_to_next(&tree, &rules);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn move_next_ignores_sticky_window_on_last_workspace() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_sticky_floating_window();
+    });
+
+    let rules = EmptinessRules::default().with_ignore_sticky(true);
+    let workflow = get_workspaces_with_rules(&tree, &rules)
+        .map(Workflow::new)
+        .unwrap();
+    let actions = workflow.move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+fn when_move_focus_to_next(tree: &Node, rules: &EmptinessRules) -> Vec<Action> {
+    let workflow = get_workspaces_with_rules(tree, rules)
+        .map(Workflow::new)
+        .unwrap();
+    workflow.move_focus_to_next().unwrap()
+}
@@ -0,0 +1,144 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build};
+use sway_workspace_extras::{
+    Action, EmptinessRules, TreeError, Workflow, Workspace, WorkspaceAssignments,
+    get_workspaces_excluding_outputs,
+};
+
+static RULES: EmptinessRules = EmptinessRules::new();
+
+#[test]
+fn next_skips_number_of_excluded_output() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(2).visible();
+        });
+    });
+
+    let actions = workflow(&tree).move_focus_to_next();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn prev_skips_number_of_excluded_output() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(3).add_focused_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(2).visible();
+        });
+    });
+
+    let actions = workflow(&tree).move_focus_to_prev();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 1 }]));
+}
+
+#[test]
+fn next_skips_number_assigned_to_excluded_output() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(5).visible();
+        });
+    });
+
+    let actions = workflow(&tree)
+        .with_workspace_assignments(WorkspaceAssignments::parse(
+            "workspace 2 output HEADLESS-1 out-1",
+        ))
+        .move_focus_to_next();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn workspaces_of_excluded_output_are_no_targets() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(2).visible().add_urgent_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_urgent();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn shift_does_not_rename_workspaces_of_excluded_output() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(2).add_window();
+            output.workspace(4).add_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(3).visible();
+        });
+    });
+
+    let actions = workflow(&tree).shift_successors().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "5".to_owned()
+            },
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned()
+            },
+        ]
+    );
+}
+
+#[test]
+fn focus_on_excluded_output_is_an_error() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).visible().add_window();
+            output.workspace(2).add_window();
+        });
+        root.output("HEADLESS-1", |output| {
+            output.workspace(3).visible().add_focused_window();
+        });
+    });
+
+    let excluded_outputs = ["HEADLESS-*".parse().unwrap()];
+    let result = get_workspaces_excluding_outputs(&tree, &RULES, &excluded_outputs);
+
+    assert!(matches!(
+        result,
+        Err(TreeError::FocusOnExcludedOutput(name)) if name == "HEADLESS-1"
+    ));
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    let excluded_outputs = ["HEADLESS-*".parse().unwrap()];
+    get_workspaces_excluding_outputs(tree, &RULES, &excluded_outputs)
+        .map(Workflow::new)
+        .unwrap()
+}
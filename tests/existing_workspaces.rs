@@ -0,0 +1,133 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, get_workspaces_of};
+
+#[test]
+fn next_skips_gap() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(4).add_window();
+    });
+
+    let actions = when_next_existing(tree, false);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 4 }]);
+}
+
+#[test]
+fn next_ignores_workspaces_of_other_outputs() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(5).add_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = when_next_existing(tree, false);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 5 }]);
+}
+
+#[test]
+fn next_stops_at_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(3).add_focused_window();
+    });
+
+    let actions = when_next_existing(tree, false);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn next_wraps_to_first_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(2).add_window();
+            output_1.workspace(4).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(1).add_window();
+        },
+    );
+
+    let actions = when_next_existing(tree, true);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn next_wrap_with_single_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = when_next_existing(tree, true);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn prev_skips_gap() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(4).add_focused_window();
+    });
+
+    let actions = when_prev_existing(tree, false);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 1 }]);
+}
+
+#[test]
+fn prev_stops_at_first_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(2).add_focused_window();
+        output.workspace(3).add_window();
+    });
+
+    let actions = when_prev_existing(tree, false);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn prev_wraps_to_last_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(2).add_focused_window();
+            output_1.workspace(4).add_window();
+        },
+        |output_2| {
+            output_2.workspace(5).add_window();
+        },
+    );
+
+    let actions = when_prev_existing(tree, true);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 4 }]);
+}
+
+fn when_next_existing(tree: Node, wrap: bool) -> Vec<Action> {
+    let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
+    workflow.move_focus_to_next_existing(wrap)
+}
+
+fn when_prev_existing(tree: Node, wrap: bool) -> Vec<Action> {
+    let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
+    workflow.move_focus_to_prev_existing(wrap)
+}
@@ -0,0 +1,343 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{
+    Action, ConflictPolicy, Workflow, WorkflowError, Workspace, get_workspaces_of,
+};
+
+// The builder assigns node ids in creation order. The root, the __i3 output
+// and its scratchpad workspace take the ids 1 to 3.
+
+#[test]
+fn single_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn moves_other_containers_to_new_workspaces() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_window()
+            .add_focused_window()
+            .add_window();
+    });
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 6,
+                workspace_num: 2
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            }
+        ]
+    );
+}
+
+#[test]
+fn keeps_split_with_focused_window() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_container(|split| {
+                split.add_window().add_focused_window();
+            })
+            .add_container(|split| {
+                split.add_window().add_window();
+            });
+    });
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(
+        actions,
+        &[Action::MoveContainerById {
+            con_id: 9,
+            workspace_num: 2
+        }]
+    );
+}
+
+#[test]
+fn shifts_successors() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1
+                .workspace(1)
+                .add_focused_window()
+                .add_window()
+                .add_window();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.named_workspace(4, "mail").add_window();
+            output_2.workspace(7).add_window();
+        },
+    );
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "4: mail".to_owned(),
+                new_workspace_name: "5: mail".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            }
+        ]
+    );
+}
+
+#[test]
+fn moves_floating_containers() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_floating_window();
+    });
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(
+        actions,
+        &[Action::MoveContainerById {
+            con_id: 7,
+            workspace_num: 2
+        }]
+    );
+}
+
+#[test]
+fn skips_numbers_used_by_other_output_with_ranges() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1
+                .workspace(1)
+                .add_focused_window()
+                .add_window()
+                .add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"]).explode();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 3
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 4
+            }
+        ])
+    );
+}
+
+#[test]
+fn starts_at_range_of_output_with_ranges() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_focused_window().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9", "out-2=11..=19"]).explode();
+
+    assert_eq!(
+        actions,
+        Ok(vec![Action::MoveContainerById {
+            con_id: 10,
+            workspace_num: 11
+        }])
+    );
+}
+
+#[test]
+fn fails_if_range_is_exhausted() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1
+                .workspace(1)
+                .add_focused_window()
+                .add_window()
+                .add_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=2"]).explode();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoFreeNumberInRange("out-1".to_owned()))
+    );
+}
+
+#[test]
+fn shifts_own_successors_with_ranges() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1
+                .workspace(1)
+                .add_focused_window()
+                .add_window()
+                .add_window();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"]).explode();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            }
+        ])
+    );
+}
+
+#[test]
+fn fail_policy_shifts_own_successors() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_window()
+            .add_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = get_workspaces_of(&tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_conflict_policy(Some(ConflictPolicy::Fail))
+        .explode();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            }
+        ])
+    );
+}
+
+#[test]
+fn shifts_successors_only_as_far_as_needed() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_window()
+            .add_window()
+            .add_window();
+        output.workspace(3).add_window();
+        output.workspace(4).add_window();
+    });
+
+    let actions = when_explode(&tree);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "5".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            }
+        ]
+    );
+}
+
+fn when_explode(tree: &Node) -> Vec<Action> {
+    let workflow = get_workspaces_of(tree).map(Workflow::new).unwrap();
+    workflow.explode().unwrap()
+}
+
+fn with_ranges<'a>(tree: &'a Node, ranges: &[&str]) -> Workflow<impl Workspace + 'a> {
+    let ranges = ranges.iter().map(|r| r.parse().unwrap()).collect();
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(ranges)
+}
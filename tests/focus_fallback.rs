@@ -0,0 +1,81 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build, two_outputs};
+use sway_workspace_extras::{Action, TreeError, Workflow, Workspace, get_workspaces_of};
+
+#[test]
+fn falls_back_to_visible_workspace_of_focused_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window();
+            output.workspace(2).visible().add_window();
+        },
+        |output| {
+            output.focused();
+            output.workspace(3).visible();
+        },
+    );
+
+    let workspaces = get_workspaces_of(&tree).unwrap();
+
+    assert_eq!(workspaces.focused_workspace().workspace_number(), 3);
+}
+
+#[test]
+fn falls_back_to_visible_workspace_when_scratchpad_window_is_focused() {
+    let tree = build(|root| {
+        root.focused_scratchpad_window();
+        root.output("out-1", |output| {
+            output.workspace(1).visible().add_window();
+            output.workspace(2).add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_focus_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn prefers_workspace_containing_focus_over_fallback() {
+    let tree = two_outputs(
+        |output| {
+            output.focused();
+            output.workspace(1).visible().add_window();
+        },
+        |output| {
+            output.workspace(2).visible().add_focused_window();
+        },
+    );
+
+    let workspaces = get_workspaces_of(&tree).unwrap();
+
+    assert_eq!(workspaces.focused_workspace().workspace_number(), 2);
+}
+
+#[test]
+fn fails_without_visible_workspace_on_focused_output() {
+    let tree = build(|root| {
+        root.focused_scratchpad_window();
+        root.output("out-1", |output| {
+            output.workspace(1).add_window();
+        });
+    });
+
+    let result = get_workspaces_of(&tree);
+
+    assert!(matches!(result, Err(TreeError::NoFocusedWorkspace())));
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
@@ -0,0 +1,166 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, get_workspaces_of};
+
+// The builder assigns node ids in creation order. The root, the __i3 output
+// and its scratchpad workspace take the ids 1 to 3.
+
+#[test]
+fn nothing_matches() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_app_window("firefox", "a");
+    });
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn ignores_windows_on_focused_workspace() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_focused_app_window("foot", "a")
+            .add_app_window("foot", "b");
+    });
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn moves_windows_from_all_outputs() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1
+                .workspace(2)
+                .add_app_window("foot", "a")
+                .add_app_window("firefox", "b");
+        },
+        |output_2| {
+            output_2
+                .workspace(3)
+                .add_app_window("foot", "c")
+                .add_window();
+        },
+    );
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 1
+            },
+            Action::MoveContainerById {
+                con_id: 12,
+                workspace_num: 1
+            }
+        ]
+    );
+}
+
+#[test]
+fn closes_gap_of_emptied_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_app_window("foot", "a");
+        output.workspace(3).add_window();
+        output.named_workspace(4, "mail").add_window();
+    });
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 1
+            },
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "2".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "4: mail".to_owned(),
+                new_workspace_name: "3: mail".to_owned(),
+            }
+        ]
+    );
+}
+
+#[test]
+fn keeps_existing_gaps() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(3).add_app_window("foot", "a");
+            output_1.workspace(4).add_window();
+            output_1.workspace(6).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 1
+            },
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            }
+        ]
+    );
+}
+
+#[test]
+fn visible_workspace_is_not_removed() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).visible().add_app_window("foot", "a");
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = when_gather(&tree, "foot");
+
+    assert_eq!(
+        actions,
+        &[Action::MoveContainerById {
+            con_id: 9,
+            workspace_num: 1
+        }]
+    );
+}
+
+fn when_gather(tree: &Node, criteria: &str) -> Vec<Action> {
+    let workflow = get_workspaces_of(tree).map(Workflow::new).unwrap();
+    workflow.gather_matching_windows(&criteria.parse().unwrap())
+}
@@ -0,0 +1,233 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+#[test]
+fn next_global_stays_on_output_with_successor() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(3).add_window();
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn next_global_continues_on_next_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window();
+            output.workspace(2).add_focused_window();
+        },
+        |output| {
+            output.workspace(3).add_window();
+            output.workspace(4).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn next_global_orders_outputs_by_position() {
+    let tree = two_outputs(
+        |output| {
+            output.rect(1920, 0, 1920, 1080);
+            output.workspace(1).add_window();
+        },
+        |output| {
+            output.rect(0, 0, 1920, 1080);
+            output.workspace(2).add_focused_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 1 }]));
+}
+
+#[test]
+fn next_global_creates_trailing_workspace_on_last_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window();
+        },
+        |output| {
+            output.workspace(2).add_focused_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 3 }]));
+}
+
+#[test]
+fn prev_global_continues_on_last_workspace_of_previous_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window();
+            output.workspace(2).add_window();
+        },
+        |output| {
+            output.workspace(3).add_focused_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_prev_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 2 }]));
+}
+
+#[test]
+fn prev_global_falls_back_to_prev_on_first_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(2).add_focused_window();
+        },
+        |output| {
+            output.workspace(3).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_prev_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 1 }]));
+}
+
+#[test]
+fn move_next_global_moves_container_to_next_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window().add_focused_window();
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_container_to_next_global();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 },
+        ])
+    );
+}
+
+#[test]
+fn move_next_global_moves_parent_container() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window().add_container(|split| {
+                split.add_focused_window().add_window();
+            });
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree)
+        .with_container_level(1)
+        .move_container_to_next_global();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::FocusParent,
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 },
+        ])
+    );
+}
+
+#[test]
+fn move_marked_next_global_moves_marked_container_to_next_output() {
+    let tree = two_outputs(
+        |output| {
+            output
+                .workspace(1)
+                .add_focused_window()
+                .add_marked_window("term");
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_marked_to_next_global("term");
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::MoveFocus { workspace_num: 2 },
+        ])
+    );
+}
+
+#[test]
+fn move_prev_global_without_follow() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_window();
+        },
+        |output| {
+            output.workspace(2).add_focused_window();
+        },
+    );
+
+    let actions = workflow(&tree)
+        .with_follow(false)
+        .move_container_to_prev_global();
+
+    assert_eq!(
+        actions,
+        Ok(vec![Action::MoveContainer { workspace_num: 1 }])
+    );
+}
+
+#[test]
+fn move_next_global_from_empty_workspace_does_nothing() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).focused();
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_container_to_next_global();
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
@@ -0,0 +1,130 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{
+    Action, Direction, Workflow, WorkflowError, Workspace, get_workspaces_of,
+};
+
+#[test]
+fn focuses_existing_workspace_below() {
+    let tree = single_output(|output| {
+        output.workspace(2).add_focused_window();
+        output.workspace(5).add_window();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"]).move_focus_in_grid(Direction::Down);
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 5 }]));
+}
+
+#[test]
+fn does_not_focus_missing_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(2).add_focused_window();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"]).move_focus_in_grid(Direction::Right);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn does_not_wrap_to_previous_row() {
+    let tree = single_output(|output| {
+        output.workspace(3).add_window();
+        output.workspace(4).add_focused_window();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"]).move_focus_in_grid(Direction::Left);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn grid_starts_at_output_range() {
+    let tree = single_output(|output| {
+        output.workspace(101).add_window();
+        output.workspace(104).add_focused_window();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"])
+        .with_output_ranges(vec!["out-1=101..=199".parse().unwrap()])
+        .move_focus_in_grid(Direction::Up);
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 101 }]));
+}
+
+#[test]
+fn move_creates_missing_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window().add_focused_window();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"]).move_container_in_grid(Direction::Down);
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::MoveContainer { workspace_num: 4 },
+            Action::MoveFocus { workspace_num: 4 },
+        ])
+    );
+}
+
+#[test]
+fn move_does_not_use_workspace_of_other_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_focused_window();
+        },
+        |output| {
+            output.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_columns(&tree, &["out-*=3"]).move_container_in_grid(Direction::Right);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn move_from_empty_workspace_does_nothing() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused();
+    });
+
+    let actions = with_columns(&tree, &["out-1=3"]).move_container_in_grid(Direction::Right);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn fails_without_columns_for_output() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = with_columns(&tree, &["out-2=3"]).move_focus_in_grid(Direction::Down);
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoGridColumns("out-1".to_owned()))
+    );
+}
+
+fn with_columns<'a>(tree: &'a Node, columns: &[&str]) -> Workflow<impl Workspace + 'a> {
+    let columns = columns.iter().map(|c| c.parse().unwrap()).collect();
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_grid_columns(columns)
+}
@@ -0,0 +1,171 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+// The builder assigns node ids in creation order. The root, the __i3 output
+// and its scratchpad workspace take the ids 1 to 3.
+
+#[test]
+fn unknown_mark() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window().add_window();
+    });
+
+    let actions = workflow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn moves_marked_container_of_unfocused_workspace_to_next() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_marked_window("term").add_window();
+        output.workspace(3).add_window();
+    });
+
+    let actions = workflow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn uses_output_of_marked_container() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_marked_window("term").add_window();
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 9,
+                workspace_num: 3
+            },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn marked_container_alone_on_last_workspace_stays() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_marked_window("term");
+    });
+
+    let actions = workflow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn moves_marked_container_to_prev() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(3).add_marked_window("term");
+        output.workspace(4).add_focused_window();
+    });
+
+    let actions = workflow(&tree).move_marked_to_prev("term").unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 2
+            },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn move_marked_here_collects_matching_containers() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1
+                .workspace(2)
+                .add_marked_window("term-1")
+                .add_window();
+        },
+        |output_2| {
+            output_2
+                .workspace(3)
+                .add_marked_window("term-2")
+                .add_marked_window("browser");
+        },
+    );
+
+    let actions = workflow(&tree).move_marked_containers_here(&"^term".parse().unwrap());
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 1
+            },
+            Action::MoveContainerById {
+                con_id: 12,
+                workspace_num: 1
+            }
+        ]
+    );
+}
+
+#[test]
+fn move_marked_here_skips_marked_containers_within_moved_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_container(|split| {
+            split
+                .mark("term-1")
+                .add_marked_window("term-2")
+                .add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_marked_containers_here(&"^term".parse().unwrap());
+
+    assert_eq!(
+        actions,
+        &[Action::MoveContainerById {
+            con_id: 8,
+            workspace_num: 1
+        }]
+    );
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
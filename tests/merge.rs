@@ -0,0 +1,151 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+// The builder assigns node ids in creation order. The root, the __i3 output
+// and its scratchpad workspace take the ids 1 to 3.
+
+#[test]
+fn merge_next_without_successor() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_focused_window();
+    });
+
+    let actions = workflow(&tree).merge_into_next(false);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn merge_next_with_empty_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused();
+        output.workspace(2).add_window();
+    });
+
+    let actions = workflow(&tree).merge_into_next(false);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn merge_next_moves_tiled_and_floating_containers() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1
+                .workspace(1)
+                .add_focused_window()
+                .add_window()
+                .add_floating_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).merge_into_next(false);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 6,
+                workspace_num: 3
+            },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 3
+            },
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn merge_next_with_compact() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_focused_window();
+        output.workspace(3).add_window();
+        output.workspace(4).add_window();
+    });
+
+    let actions = workflow(&tree).merge_into_next(true);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 3
+            },
+            Action::MoveFocus { workspace_num: 3 },
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "2".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            }
+        ]
+    );
+}
+
+#[test]
+fn merge_prev_skips_workspaces_of_other_outputs() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).merge_into_prev(false);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainerById {
+                con_id: 8,
+                workspace_num: 1
+            },
+            Action::MoveFocus { workspace_num: 1 }
+        ]
+    );
+}
+
+#[test]
+fn merge_prev_without_predecessor() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = workflow(&tree).merge_into_prev(true);
+
+    assert_eq!(actions, &[]);
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
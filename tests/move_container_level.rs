@@ -0,0 +1,139 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+#[test]
+fn moves_parent_to_next_workspace() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_container(|tabs| {
+                tabs.add_focused_window().add_window();
+            })
+            .add_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = with_level(&tree, 1).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusParent,
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn level_is_limited_to_top_level_container() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_container(|split| {
+                split.add_container(|tabs| {
+                    tabs.add_focused_window().add_window();
+                });
+            })
+            .add_window();
+    });
+
+    let actions = with_level(&tree, 5).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusParent,
+            Action::FocusParent,
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn parent_without_other_containers_creates_no_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|tabs| {
+            tabs.add_focused_window().add_window();
+        });
+    });
+
+    let actions = with_level(&tree, 1).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn focused_window_of_group_creates_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|tabs| {
+            tabs.add_focused_window().add_window();
+        });
+    });
+
+    let actions = with_level(&tree, 0).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn moves_parent_to_prev_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(2).add_container(|tabs| {
+            tabs.add_focused_window().add_window();
+        });
+    });
+
+    let actions = with_level(&tree, 1).move_container_to_prev().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusParent,
+            Action::MoveContainer { workspace_num: 1 },
+            Action::MoveFocus { workspace_num: 1 }
+        ]
+    );
+}
+
+#[test]
+fn focused_top_level_window_has_no_parent_to_select() {
+    let tree = single_output(|output| {
+        output.workspace(2).add_focused_window();
+    });
+
+    let actions = with_level(&tree, 1).move_container_to_prev().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 1 },
+            Action::MoveFocus { workspace_num: 1 }
+        ]
+    );
+}
+
+fn with_level(tree: &Node, level: usize) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_container_level(level)
+}
@@ -0,0 +1,220 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build, single_output, two_outputs};
+use sway_workspace_extras::{Action, Direction, Workflow, get_workspaces_of};
+
+#[test]
+fn single_output_has_no_neighbour() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn empty_focused_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).focused();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn no_output_in_direction() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Left);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn uses_trailing_empty_workspace_of_neighbour() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+            output_2.workspace(3);
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 3 },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn creates_trailing_workspace_on_neighbour() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 3 },
+            Action::MoveFocus { workspace_num: 3 },
+            Action::MoveWorkspaceToOutput {
+                output_name: "out-2".to_owned()
+            }
+        ]
+    );
+}
+
+#[test]
+fn moves_to_left_neighbour() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_focused_window();
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Left);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            },
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 },
+            Action::MoveWorkspaceToOutput {
+                output_name: "out-1".to_owned()
+            }
+        ]
+    );
+}
+
+#[test]
+fn shifts_successors_of_trailing_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::MoveContainer { workspace_num: 3 },
+            Action::MoveFocus { workspace_num: 3 },
+            Action::MoveWorkspaceToOutput {
+                output_name: "out-2".to_owned()
+            }
+        ]
+    );
+}
+
+#[test]
+fn moves_to_output_below() {
+    let tree = build(|root| {
+        root.output("top", |output| {
+            output.rect(0, 0, 1920, 1080);
+            output.workspace(1).add_focused_window();
+        });
+        root.output("bottom", |output| {
+            output.rect(0, 1080, 1920, 1080);
+            output.workspace(2);
+        });
+    });
+
+    let actions = when_move_container_to_output(tree, Direction::Down);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn picks_nearest_output_in_direction() {
+    let tree = build(|root| {
+        root.output("far", |output| {
+            output.rect(3840, 0, 1920, 1080);
+            output.workspace(3);
+        });
+        root.output("left", |output| {
+            output.rect(0, 0, 1920, 1080);
+            output.workspace(1).add_focused_window();
+        });
+        root.output("near", |output| {
+            output.rect(1920, 0, 1920, 1080);
+            output.workspace(2);
+        });
+    });
+
+    let actions = when_move_container_to_output(tree, Direction::Right);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+fn when_move_container_to_output(tree: Node, direction: Direction) -> Vec<Action> {
+    let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
+    workflow.move_container_to_output(direction).unwrap()
+}
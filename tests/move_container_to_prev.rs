@@ -383,5 +383,5 @@ fn supports_named_workspaces() {
 
 fn when_move_container_to_prev(tree: Node) -> Vec<Action> {
     let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
-    workflow.move_container_to_prev()
+    workflow.move_container_to_prev().unwrap()
 }
@@ -320,5 +320,5 @@ fn supports_named_workspaces() {
 
 fn when_move_focus_to_next(tree: Node) -> Vec<Action> {
     let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
-    workflow.move_focus_to_next()
+    workflow.move_focus_to_next().unwrap()
 }
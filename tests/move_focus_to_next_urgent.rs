@@ -0,0 +1,94 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, get_workspaces_of};
+
+#[test]
+fn no_urgent_window() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = when_move_focus_to_next_urgent(tree);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn urgent_window_on_focused_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window().add_urgent_window();
+    });
+
+    let actions = when_move_focus_to_next_urgent(tree);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn prefers_successor_on_current_output() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_urgent_window();
+        output.workspace(2).add_focused_window();
+        output.workspace(3).add_window();
+        output.workspace(4).add_urgent_window();
+    });
+
+    let actions = when_move_focus_to_next_urgent(tree);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 4 }]);
+}
+
+#[test]
+fn falls_back_to_predecessor_on_current_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_urgent_window();
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(4).add_urgent_window();
+        },
+    );
+
+    let actions = when_move_focus_to_next_urgent(tree);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 1 }]);
+}
+
+#[test]
+fn searches_other_outputs_from_left_to_right() {
+    let tree = build(|root| {
+        root.output("right", |output| {
+            output.rect(3840, 0, 1920, 1080);
+            output.workspace(2).add_urgent_window();
+        });
+        root.output("middle", |output| {
+            output.rect(1920, 0, 1920, 1080);
+            output.workspace(1).add_focused_window();
+        });
+        root.output("left", |output| {
+            output.rect(0, 0, 1920, 1080);
+            output.workspace(3).add_urgent_window();
+        });
+    });
+
+    let actions = when_move_focus_to_next_urgent(tree);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+fn when_move_focus_to_next_urgent(tree: Node) -> Vec<Action> {
+    let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
+    workflow.move_focus_to_next_urgent()
+}
@@ -0,0 +1,114 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+// The builder assigns node ids in creation order. The root, the __i3 output
+// and its scratchpad workspace take the ids 1 to 3.
+
+#[test]
+fn move_next_keeps_focus() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window().add_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = without_follow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveContainer { workspace_num: 2 }]);
+}
+
+#[test]
+fn move_next_creates_workspace_for_last_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = without_follow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveContainer { workspace_num: 2 }]);
+}
+
+#[test]
+fn move_next_shifts_successors() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = without_follow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            },
+            Action::MoveContainer { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn move_prev_keeps_focus() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_focused_window();
+    });
+
+    let actions = without_follow(&tree).move_container_to_prev().unwrap();
+
+    assert_eq!(actions, &[Action::MoveContainer { workspace_num: 1 }]);
+}
+
+#[test]
+fn marked_container_of_focused_workspace_gets_new_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.add_focused_window().add_marked_window("term");
+        });
+    });
+
+    let actions = without_follow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(
+        actions,
+        &[Action::MoveContainerById {
+            con_id: 8,
+            workspace_num: 2
+        }]
+    );
+}
+
+#[test]
+fn marked_container_alone_on_unfocused_workspace_stays() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_marked_window("term");
+    });
+
+    let actions = without_follow(&tree).move_marked_to_next("term").unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+fn without_follow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_follow(false)
+}
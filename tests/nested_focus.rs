@@ -0,0 +1,306 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+#[test]
+fn detects_focused_workspace_with_nested_focus() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.add_window().add_container(|inner| {
+                inner.add_window().add_focused_window();
+            });
+        });
+        output.workspace(2).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn detects_focused_workspace_node() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).focused().add_window().add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_prev().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 1 }]);
+}
+
+#[test]
+fn single_nested_window_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_container(|split| {
+            split.add_container(|split| {
+                split.add_focused_window();
+            });
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn nested_window_with_sibling_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.add_window().add_focused_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn focused_middle_window_of_container_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.add_window().add_focused_window().add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn single_window_in_nested_containers_on_only_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|outer| {
+            outer.add_container(|split| {
+                split.add_focused_window();
+            });
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn focused_parent_container_alone_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.focused().add_window().add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn focused_parent_container_with_sibling_on_last_workspace() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_container(|split| {
+                split.focused().add_window().add_window();
+            })
+            .add_window();
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn floating_window_next_to_nested_focus() {
+    let tree = single_output(|output| {
+        output
+            .workspace(1)
+            .add_container(|split| {
+                split.add_focused_window();
+            })
+            .add_floating_window();
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn focused_tab_with_other_tabs_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_tabbed_container(|tabs| {
+            tabs.add_window().add_focused_window().add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn focused_tabbed_container_alone_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_tabbed_container(|tabs| {
+            tabs.focused().add_window().add_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn single_window_in_stacked_container_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_stacked_container(|stack| {
+            stack.add_focused_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn fullscreen_window_with_sibling_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|split| {
+            split.add_window().add_focused_fullscreen_window();
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 2 },
+            Action::MoveFocus { workspace_num: 2 }
+        ]
+    );
+}
+
+#[test]
+fn single_fullscreen_window_in_nested_containers_on_last_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_container(|outer| {
+            outer.add_container(|split| {
+                split.add_focused_fullscreen_window();
+            });
+        });
+    });
+
+    let actions = workflow(&tree).move_container_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn is_focused_follows_focus_into_tabbed_and_stacked_containers() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_tabbed_container(|tabs| {
+            tabs.add_window().add_stacked_container(|stack| {
+                stack.add_window().add_focused_window();
+            });
+        });
+        output.workspace(2).add_tabbed_container(|tabs| {
+            tabs.add_window().add_window();
+        });
+    });
+
+    let workspaces = get_workspaces_of(&tree).unwrap();
+
+    assert!(workspaces.find_by_number(1).unwrap().is_focused());
+    assert!(!workspaces.find_by_number(2).unwrap().is_focused());
+}
+
+#[test]
+fn fullscreen_tab_next_to_hidden_tab_contains_not_focused_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_tabbed_container(|tabs| {
+            tabs.add_focused_fullscreen_window().add_window();
+        });
+    });
+
+    let workspaces = get_workspaces_of(&tree).unwrap();
+
+    assert!(
+        workspaces
+            .focused_workspace()
+            .contains_not_focused_container()
+    );
+}
+
+#[test]
+fn fullscreen_window_alone_in_tabs_contains_no_other_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_tabbed_container(|tabs| {
+            tabs.add_stacked_container(|stack| {
+                stack.add_focused_fullscreen_window();
+            });
+        });
+    });
+
+    let workspaces = get_workspaces_of(&tree).unwrap();
+
+    assert!(
+        !workspaces
+            .focused_workspace()
+            .contains_not_focused_container()
+    );
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
@@ -7,10 +7,20 @@
  * received a copy of the license along with this program.
  */
 
+// Each integration test compiles its own copy of this module and only uses
+// the parts of the builder it needs.
+#![allow(dead_code)]
+
 mod node;
 
 pub use self::node::Node;
 
+use sway_workspace_extras::Rect;
+use swayipc::NodeLayout;
+
+const OUTPUT_WIDTH: i32 = 1920;
+const OUTPUT_HEIGHT: i32 = 1080;
+
 pub fn single_output<F>(setup: F) -> Node
 where
     F: FnOnce(&mut OutputBuilder),
@@ -45,6 +55,7 @@ where
         out.scratch_workspace();
     });
     setup(&mut builder);
+    tree.assign_focus_stacks();
     tree
 }
 
@@ -73,6 +84,7 @@ impl TreeBuilder<'_> {
         F: FnOnce(&mut OutputBuilder),
     {
         let mut output = Node::create_output(self.id.next(), name);
+        output.rect = Rect::new(self.next_output_x(), 0, OUTPUT_WIDTH, OUTPUT_HEIGHT);
         let mut builder = OutputBuilder {
             id: self.id,
             output: &mut output,
@@ -80,6 +92,36 @@ impl TreeBuilder<'_> {
         setup(&mut builder);
         self.tree.nodes.push(output);
     }
+
+    /// Adds a focused window to the scratchpad workspace of the __i3 output.
+    pub fn focused_scratchpad_window(&mut self) {
+        let mut window = Node::create_named_node(self.id.next(), "Window");
+        window.is_focused = true;
+        self.tree.nodes[0].nodes[0].floating_nodes.push(window);
+    }
+
+    /// Adds a window to the scratchpad workspace of the __i3 output.
+    pub fn scratchpad_window(&mut self) {
+        let window = Node::create_named_node(self.id.next(), "Window");
+        self.tree.nodes[0].nodes[0].floating_nodes.push(window);
+    }
+
+    /// Adds a window to the scratchpad with the mark `stash` gives it.
+    pub fn stashed_window(&mut self, origin: i32) {
+        let id = self.id.next();
+        let mut window = Node::create_named_node(id, "Window");
+        window.marks.push(format!("_stash:{origin}:{id}"));
+        self.tree.nodes[0].nodes[0].floating_nodes.push(window);
+    }
+
+    fn next_output_x(&self) -> i32 {
+        self.tree
+            .nodes
+            .iter()
+            .map(|output| output.rect.x + output.rect.width)
+            .max()
+            .unwrap_or(0)
+    }
 }
 
 pub struct OutputBuilder<'a> {
@@ -88,11 +130,19 @@ pub struct OutputBuilder<'a> {
 }
 
 impl OutputBuilder<'_> {
-    pub fn workspace(&mut self, num: i32) -> WorkspaceBuilder {
+    pub fn focused(&mut self) {
+        self.output.is_focused = true;
+    }
+
+    pub fn rect(&mut self, x: i32, y: i32, width: i32, height: i32) {
+        self.output.rect = Rect::new(x, y, width, height);
+    }
+
+    pub fn workspace(&mut self, num: i32) -> WorkspaceBuilder<'_> {
         self.add_workspace(Some(num)).name(format!("{num}"))
     }
 
-    pub fn named_workspace(&mut self, num: i32, name: &str) -> WorkspaceBuilder {
+    pub fn named_workspace(&mut self, num: i32, name: &str) -> WorkspaceBuilder<'_> {
         self.add_workspace(Some(num)).name(format!("{num}: {name}"))
     }
 
@@ -101,7 +151,7 @@ impl OutputBuilder<'_> {
         builder.name("__i3_scratch");
     }
 
-    fn add_workspace(&mut self, num: Option<i32>) -> WorkspaceBuilder {
+    fn add_workspace(&mut self, num: Option<i32>) -> WorkspaceBuilder<'_> {
         self.output
             .nodes
             .push(Node::create_workspace(self.id.next(), num));
@@ -124,11 +174,21 @@ impl WorkspaceBuilder<'_> {
         self
     }
 
+    pub fn visible(self) -> Self {
+        self.workspace.is_visible = true;
+        self
+    }
+
     pub fn name<T: Into<String>>(self, name: T) -> Self {
         self.workspace.name = Some(name.into());
         self
     }
 
+    pub fn mark(self, mark: &str) -> Self {
+        self.workspace.marks.push(mark.to_owned());
+        self
+    }
+
     pub fn add_focused_window(self) -> Self {
         let mut node = Node::create_named_node(self.id.next(), "Window");
         node.is_focused = true;
@@ -136,6 +196,106 @@ impl WorkspaceBuilder<'_> {
         self
     }
 
+    pub fn add_urgent_window(self) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), "Window");
+        node.is_urgent = true;
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    pub fn add_app_window(self, app_id: &str, title: &str) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), title);
+        node.app_id = Some(app_id.to_owned());
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    pub fn add_focused_app_window(self, app_id: &str, title: &str) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), title);
+        node.app_id = Some(app_id.to_owned());
+        node.is_focused = true;
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    pub fn add_x11_window(self, class: &str, title: &str) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), title);
+        node.window_class = Some(class.to_owned());
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    pub fn add_marked_window(self, mark: &str) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), "Window");
+        node.marks.push(mark.to_owned());
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    pub fn add_floating_window(self) -> Self {
+        let node = Node::create_named_node(self.id.next(), "Window");
+        self.workspace.floating_nodes.push(node);
+        self
+    }
+
+    pub fn add_sticky_floating_window(self) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), "Window");
+        node.is_sticky = true;
+        self.workspace.floating_nodes.push(node);
+        self
+    }
+
+    pub fn add_floating_app_window(self, app_id: &str, title: &str) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), title);
+        node.app_id = Some(app_id.to_owned());
+        self.workspace.floating_nodes.push(node);
+        self
+    }
+
+    pub fn add_focused_fullscreen_window(self) -> Self {
+        let mut node = Node::create_named_node(self.id.next(), "Window");
+        node.is_focused = true;
+        node.fullscreen_mode = 1;
+        self.workspace.nodes.push(node);
+        self
+    }
+
+    /// Adds a split container whose children are set up by the closure.
+    pub fn add_container<F>(self, setup: F) -> Self
+    where
+        F: FnOnce(WorkspaceBuilder<'_>),
+    {
+        self.add_container_with_layout(NodeLayout::SplitH, setup)
+    }
+
+    pub fn add_tabbed_container<F>(self, setup: F) -> Self
+    where
+        F: FnOnce(WorkspaceBuilder<'_>),
+    {
+        self.add_container_with_layout(NodeLayout::Tabbed, setup)
+    }
+
+    pub fn add_stacked_container<F>(self, setup: F) -> Self
+    where
+        F: FnOnce(WorkspaceBuilder<'_>),
+    {
+        self.add_container_with_layout(NodeLayout::Stacked, setup)
+    }
+
+    fn add_container_with_layout<F>(self, layout: NodeLayout, setup: F) -> Self
+    where
+        F: FnOnce(WorkspaceBuilder<'_>),
+    {
+        let mut node = Node::create_named_node(self.id.next(), "Container");
+        node.layout = layout;
+        self.workspace.nodes.push(node);
+        setup(WorkspaceBuilder {
+            id: self.id,
+            workspace: self.workspace.nodes.last_mut().unwrap(),
+        });
+        self
+    }
+
     pub fn add_window(self) -> Self {
         let node = Node::create_named_node(self.id.next(), "Window");
         self.workspace.nodes.push(node);
@@ -7,7 +7,8 @@
  * received a copy of the license along with this program.
  */
 
-use sway_workspace_extras::{NamedNode, NodeWithChildren, SwayNode};
+use sway_workspace_extras::{NamedNode, NodeWithChildren, Rect, SwayNode};
+use swayipc::NodeLayout;
 
 pub struct Node {
     id: i64,
@@ -16,8 +17,18 @@ pub struct Node {
     num: Option<i32>,
     pub(super) name: Option<String>,
     pub(super) is_focused: bool,
+    pub(super) focus: Vec<i64>,
+    pub(super) is_urgent: bool,
+    pub(super) is_visible: bool,
+    pub(super) is_sticky: bool,
+    pub(super) app_id: Option<String>,
+    pub(super) window_class: Option<String>,
+    pub(super) marks: Vec<String>,
+    pub(super) layout: NodeLayout,
+    pub(super) fullscreen_mode: u8,
+    pub(super) rect: Rect,
     pub(super) nodes: Vec<Node>,
-    floating_nodes: Vec<Node>,
+    pub(super) floating_nodes: Vec<Node>,
 }
 
 impl Node {
@@ -29,6 +40,16 @@ impl Node {
             num: None,
             name: Some(name.to_owned()),
             is_focused: false,
+            focus: vec![],
+            is_urgent: false,
+            is_visible: false,
+            is_sticky: false,
+            app_id: None,
+            window_class: None,
+            marks: vec![],
+            layout: NodeLayout::None,
+            fullscreen_mode: 0,
+            rect: Rect::default(),
             nodes: vec![],
             floating_nodes: vec![],
         }
@@ -48,12 +69,39 @@ impl Node {
             num,
             name: None,
             is_focused: false,
+            focus: vec![],
+            is_urgent: false,
+            is_visible: false,
+            is_sticky: false,
+            app_id: None,
+            window_class: None,
+            marks: vec![],
+            layout: NodeLayout::SplitH,
+            fullscreen_mode: 0,
+            rect: Rect::default(),
             nodes: vec![],
             floating_nodes: vec![],
         }
     }
 }
 
+impl Node {
+    /// Fills the focus stacks like Sway does: the child that contains the
+    /// focused node comes first, followed by the others in tree order.
+    pub fn assign_focus_stacks(&mut self) -> bool {
+        let mut children_with_focus = vec![];
+        for child in self.nodes.iter_mut().chain(self.floating_nodes.iter_mut()) {
+            let contains_focus = child.assign_focus_stacks();
+            children_with_focus.push((child.id, contains_focus));
+        }
+
+        children_with_focus.sort_by_key(|(_, contains_focus)| !contains_focus);
+        self.focus = children_with_focus.iter().map(|(id, _)| *id).collect();
+
+        self.is_focused || children_with_focus.iter().any(|(_, focus)| *focus)
+    }
+}
+
 impl NamedNode for Node {
     fn get_name(&self) -> &Option<String> {
         &self.name
@@ -91,6 +139,38 @@ impl SwayNode for Node {
         self.is_focused
     }
 
+    fn get_focus(&self) -> &[i64] {
+        &self.focus
+    }
+
+    fn is_urgent(&self) -> bool {
+        self.is_urgent
+    }
+
+    fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    fn is_sticky(&self) -> bool {
+        self.is_sticky
+    }
+
+    fn get_app_id(&self) -> Option<&str> {
+        self.app_id.as_deref()
+    }
+
+    fn get_window_class(&self) -> Option<&str> {
+        self.window_class.as_deref()
+    }
+
+    fn get_marks(&self) -> &[String] {
+        &self.marks
+    }
+
+    fn get_rect(&self) -> Rect {
+        self.rect
+    }
+
     fn find_as_ref<F>(&self, predicate: F) -> Option<&Self>
     where
         F: Copy + Fn(&Self) -> bool,
@@ -0,0 +1,197 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, WorkspaceAssignments, get_workspaces_of};
+
+#[test]
+fn next_occupied_skips_empty_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2);
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_occupied(false);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn next_occupied_without_occupied_successor() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2);
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_occupied(false);
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn next_occupied_wraps_to_first_occupied_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1);
+        output.workspace(2).add_window();
+        output.workspace(3).add_focused_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_occupied(true);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn prev_occupied_skips_empty_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2);
+        output.workspace(3).focused();
+    });
+
+    let actions = workflow(&tree).move_focus_to_prev_occupied(false);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 1 }]);
+}
+
+#[test]
+fn next_empty_uses_gap() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+        output.workspace(4).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn next_empty_uses_nearer_gap_before_focused_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(3).add_focused_window();
+        output.workspace(4).add_window();
+        output.workspace(5).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn next_empty_prefers_later_gap_on_equal_distance() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(3).add_focused_window();
+        output.workspace(5).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 4 }]);
+}
+
+#[test]
+fn next_empty_prefers_nearer_trailing_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(3).add_window();
+        output.workspace(4).add_focused_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 5 }]);
+}
+
+#[test]
+fn next_empty_skips_workspaces_of_other_outputs() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2);
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "5".to_owned(),
+            },
+            Action::MoveFocus { workspace_num: 4 }
+        ]
+    );
+}
+
+#[test]
+fn next_empty_skips_assigned_gap() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = workflow(&tree)
+        .with_workspace_assignments(WorkspaceAssignments::parse("workspace 2 output out-2"))
+        .move_focus_to_next_empty()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 4 }]);
+}
+
+#[test]
+fn next_empty_creates_trailing_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn next_empty_on_empty_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused();
+        output.workspace(2).add_window();
+    });
+
+    let actions = workflow(&tree).move_focus_to_next_empty().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
@@ -0,0 +1,109 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build};
+use sway_workspace_extras::{Action, Workflow, get_workspaces_of};
+
+#[test]
+fn side_by_side_outputs_are_ordered_from_left_to_right() {
+    let tree = with_outputs(&[("right", 1920, 0, 1920, 1080), ("left", 0, 0, 1920, 1080)]);
+
+    assert_eq!(output_order(&tree), &["left", "right"]);
+}
+
+#[test]
+fn stacked_outputs_are_ordered_from_top_to_bottom() {
+    let tree = with_outputs(&[("bottom", 0, 1080, 1920, 1080), ("top", 0, 0, 1920, 1080)]);
+
+    assert_eq!(output_order(&tree), &["top", "bottom"]);
+}
+
+#[test]
+fn rotated_output_shares_row_with_its_neighbour() {
+    let tree = with_outputs(&[
+        ("landscape", 1080, 420, 1920, 1080),
+        ("portrait", 0, 0, 1080, 1920),
+    ]);
+
+    assert_eq!(output_order(&tree), &["portrait", "landscape"]);
+}
+
+#[test]
+fn outputs_with_different_scales_share_row() {
+    let tree = with_outputs(&[
+        ("laptop", 2560, 400, 1280, 800),
+        ("scaled-4k", 0, 0, 2560, 1440),
+        ("below", 0, 1440, 1920, 1080),
+    ]);
+
+    assert_eq!(output_order(&tree), &["scaled-4k", "laptop", "below"]);
+}
+
+#[test]
+fn grid_of_outputs_is_ordered_row_by_row() {
+    let tree = with_outputs(&[
+        ("bottom-right", 1920, 1080, 1920, 1080),
+        ("top-right", 1920, 0, 1920, 1080),
+        ("bottom-left", 0, 1080, 1920, 1080),
+        ("top-left", 0, 0, 1920, 1080),
+    ]);
+
+    assert_eq!(
+        output_order(&tree),
+        &["top-left", "top-right", "bottom-left", "bottom-right"]
+    );
+}
+
+#[test]
+fn next_global_continues_on_output_below() {
+    let tree = build(|root| {
+        root.output("bottom", |output| {
+            output.rect(0, 1080, 1920, 1080);
+            output.workspace(2).add_window();
+        });
+        root.output("top", |output| {
+            output.rect(0, 0, 1920, 1080);
+            output.workspace(1).add_focused_window();
+        });
+    });
+
+    let actions = get_workspaces_of(&tree)
+        .map(Workflow::new)
+        .unwrap()
+        .move_focus_to_next_global();
+
+    assert_eq!(actions, Ok(vec![Action::MoveFocus { workspace_num: 2 }]));
+}
+
+fn with_outputs(outputs: &[(&str, i32, i32, i32, i32)]) -> Node {
+    build(|root| {
+        for (index, (name, x, y, width, height)) in outputs.iter().enumerate() {
+            root.output(name, |output| {
+                output.rect(*x, *y, *width, *height);
+                let workspace = output.workspace(index as i32 + 1);
+                if index == 0 {
+                    workspace.add_focused_window();
+                } else {
+                    workspace.add_window();
+                }
+            });
+        }
+    })
+}
+
+fn output_order(tree: &Node) -> Vec<String> {
+    get_workspaces_of(tree)
+        .unwrap()
+        .output_names_by_position()
+        .into_iter()
+        .map(str::to_owned)
+        .collect()
+}
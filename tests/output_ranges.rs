@@ -0,0 +1,272 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, two_outputs};
+use sway_workspace_extras::{
+    Action, Direction, Workflow, WorkflowError, Workspace, get_workspaces_of,
+};
+
+#[test]
+fn next_creates_trailing_workspace_within_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9", "out-2=11..=19"])
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn next_does_not_leave_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(9).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"])
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn next_jumps_to_range_start_if_below_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_focused_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=11..=19"])
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 11 }]);
+}
+
+#[test]
+fn move_next_jumps_to_range_start_if_below_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_focused_window().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=11..=19"])
+        .move_container_to_next()
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 11 },
+            Action::MoveFocus { workspace_num: 11 }
+        ]
+    );
+}
+
+#[test]
+fn next_skips_number_used_by_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"])
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn move_next_skips_number_used_by_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window().add_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"])
+        .move_container_to_next()
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 3 },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn prev_does_not_leave_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_focused_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=11..=19"])
+        .move_focus_to_prev()
+        .unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn prev_creates_predecessor_within_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+        },
+        |output_2| {
+            output_2.workspace(13).add_focused_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=11..=19"])
+        .move_focus_to_prev()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 12 }]);
+}
+
+#[test]
+fn shift_only_renames_workspaces_of_current_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).focused();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"]).shift_successors();
+
+    assert_eq!(
+        actions,
+        Ok(vec![Action::RenameWorkspace {
+            workspace_name: "2".to_owned(),
+            new_workspace_name: "3".to_owned(),
+        }])
+    );
+}
+
+#[test]
+fn shift_never_renames_workspaces_of_other_outputs() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).focused();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=9"]).shift_successors();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 3,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn shift_does_not_leave_range() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).focused();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=1..=2"]).shift_successors();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoFreeNumberInRange("out-1".to_owned()))
+    );
+}
+
+#[test]
+fn move_to_output_creates_workspace_in_range_of_target_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(11).add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=11..=19", "out-*=1..=9"])
+        .move_container_to_output(Direction::Right)
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 12 },
+            Action::MoveFocus { workspace_num: 12 },
+            Action::MoveWorkspaceToOutput {
+                output_name: "out-2".to_owned()
+            }
+        ]
+    );
+}
+
+fn with_ranges<'a>(tree: &'a Node, ranges: &[&str]) -> Workflow<impl Workspace + 'a> {
+    let ranges = ranges.iter().map(|r| r.parse().unwrap()).collect();
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(ranges)
+}
@@ -0,0 +1,236 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{
+    Action, NumberRange, Workflow, WorkflowError, Workspace, get_workspaces_of,
+};
+
+#[test]
+fn next_does_not_leave_project() {
+    let tree = single_output(|output| {
+        output.workspace(100).add_window();
+        output.workspace(101).add_focused_window();
+        output.workspace(200).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 101).move_focus_to_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn next_creates_trailing_workspace_in_project() {
+    let tree = single_output(|output| {
+        output.workspace(100).add_focused_window();
+        output.workspace(200).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 101 }]);
+}
+
+#[test]
+fn prev_does_not_leave_project() {
+    let tree = single_output(|output| {
+        output.workspace(5).add_window();
+        output.workspace(100).add_focused_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_to_prev().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn next_from_outside_enters_project() {
+    let tree = single_output(|output| {
+        output.workspace(5).add_focused_window();
+        output.workspace(100).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_to_next().unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 100 }]);
+}
+
+#[test]
+fn shift_renames_workspaces_of_project() {
+    let tree = single_output(|output| {
+        output.workspace(100).add_focused_window();
+        output.workspace(101).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).shift_successors();
+
+    assert_eq!(
+        actions,
+        Ok(vec![Action::RenameWorkspace {
+            workspace_name: "101".to_owned(),
+            new_workspace_name: "102".to_owned()
+        }])
+    );
+}
+
+#[test]
+fn shift_fails_if_project_is_full() {
+    let tree = single_output(|output| {
+        output.workspace(100).add_focused_window();
+        output.workspace(101).add_window();
+        output.workspace(102).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 101).shift_successors();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoFreeNumberInRange("out-1".to_owned()))
+    );
+}
+
+#[test]
+fn project_range_is_intersected_with_output_range() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(100).add_focused_window();
+        },
+        |output| {
+            output.workspace(150).add_window();
+        },
+    );
+
+    let actions = get_workspaces_of(&tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(vec!["out-1=1..=100".parse().unwrap()])
+        .with_project_range(Some(NumberRange::new(100, 199)))
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn shift_fails_if_project_is_full_and_next_number_is_used_by_other_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(100).add_focused_window();
+            output.workspace(101).add_window();
+        },
+        |output| {
+            output.workspace(102).add_window();
+        },
+    );
+
+    let actions = in_project(&tree, 100, 101).shift_successors();
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoFreeNumberInRange("out-1".to_owned()))
+    );
+}
+
+#[test]
+fn group_switches_output_without_workspace_of_project() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(101).add_focused_window();
+        },
+        |output| {
+            output.workspace(5).visible().add_window();
+        },
+    );
+
+    let actions = get_workspaces_of(&tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(vec![
+            "out-1=100..=149".parse().unwrap(),
+            "out-2=150..=199".parse().unwrap(),
+        ])
+        .with_project_range(Some(NumberRange::new(100, 199)))
+        .focus_group(1);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusOutput {
+                output_name: "out-2".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 150 },
+            Action::FocusOutput {
+                output_name: "out-1".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 100 },
+        ]
+    );
+}
+
+#[test]
+fn switching_focuses_lowest_workspace_of_project() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(120).add_window();
+        output.workspace(130).add_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_into_range(NumberRange::new(100, 199));
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 120 }]);
+}
+
+#[test]
+fn switching_prefers_workspace_of_current_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(130).add_window();
+        },
+        |output| {
+            output.workspace(120).add_window();
+        },
+    );
+
+    let actions = in_project(&tree, 100, 199).move_focus_into_range(NumberRange::new(100, 199));
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 130 }]);
+}
+
+#[test]
+fn switching_to_project_without_workspaces_focuses_its_first_number() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_into_range(NumberRange::new(100, 199));
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 100 }]);
+}
+
+#[test]
+fn switching_does_nothing_if_focused_workspace_is_in_project() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(110).add_focused_window();
+    });
+
+    let actions = in_project(&tree, 100, 199).move_focus_into_range(NumberRange::new(100, 199));
+
+    assert_eq!(actions, &[]);
+}
+
+fn in_project(tree: &Node, start: i32, end: i32) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_project_range(Some(NumberRange::new(start, end)))
+}
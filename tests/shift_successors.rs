@@ -252,5 +252,5 @@ fn preserves_workspaces_names() {
 
 fn when_shift_successors(tree: Node) -> Vec<Action> {
     let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
-    workflow.shift_successors()
+    workflow.shift_successors().unwrap()
 }
@@ -0,0 +1,225 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, single_output, two_outputs};
+use sway_workspace_extras::{Action, ConflictPolicy, Workflow, WorkflowError, get_workspaces_of};
+
+#[test]
+fn no_successors() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Fail);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn successors_on_same_output() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+        output.workspace(2).add_window();
+        output.workspace(3).add_window();
+    });
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Fail);
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            }
+        ])
+    );
+}
+
+#[test]
+fn gap_before_workspace_of_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Fail);
+
+    assert_eq!(
+        actions,
+        Ok(vec![Action::RenameWorkspace {
+            workspace_name: "2".to_owned(),
+            new_workspace_name: "3".to_owned(),
+        }])
+    );
+}
+
+#[test]
+fn fails_if_number_is_taken_by_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Fail);
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 3,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn skips_number_taken_by_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2).add_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(4).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Skip);
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "3".to_owned(),
+                new_workspace_name: "5".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned(),
+            }
+        ])
+    );
+}
+
+#[test]
+fn keeps_order_when_skipping_number_taken_by_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2).add_window();
+            output_1.workspace(4).add_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Skip);
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "4".to_owned(),
+                new_workspace_name: "5".to_owned(),
+            },
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned(),
+            }
+        ])
+    );
+}
+
+#[test]
+fn fails_if_next_workspace_belongs_to_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Fail);
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NumberTakenByOtherOutput {
+            workspace_number: 2,
+            output_name: "out-2".to_owned(),
+        })
+    );
+}
+
+#[test]
+fn skips_if_next_workspace_belongs_to_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(2).add_window();
+        },
+    );
+
+    let actions = when_shift_successors_on_output(tree, ConflictPolicy::Skip);
+
+    assert_eq!(actions, Ok(vec![]));
+}
+
+#[test]
+fn fails_if_range_has_no_free_number() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(2).add_window();
+        },
+        |output_2| {
+            output_2.workspace(3).add_window();
+        },
+    );
+
+    let workflow = get_workspaces_of(&tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(vec!["out-1=1..=3".parse().unwrap()]);
+    let actions = workflow.shift_successors_on_output(ConflictPolicy::Skip);
+
+    assert_eq!(
+        actions,
+        Err(WorkflowError::NoFreeNumberInRange("out-1".to_owned()))
+    );
+}
+
+fn when_shift_successors_on_output(
+    tree: Node,
+    policy: ConflictPolicy,
+) -> Result<Vec<Action>, WorkflowError> {
+    let workflow = get_workspaces_of(&tree).map(Workflow::new).unwrap();
+    workflow.shift_successors_on_output(policy)
+}
@@ -0,0 +1,307 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, build, single_output};
+use sway_workspace_extras::{Action, ConflictPolicy, Workflow, Workspace, get_workspaces_of};
+
+// The builder assigns ids in creation order: root is 1, the __i3 output 2
+// and its scratchpad workspace 3. The ids of the other nodes follow.
+
+#[test]
+fn stash_marks_focused_window_and_moves_it_to_scratchpad() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_window().add_focused_window();
+    });
+
+    let actions = workflow(&tree).stash();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MarkContainer {
+                con_id: 9,
+                mark: "_stash:2:9".to_owned()
+            },
+            Action::MoveContainerToScratchpad { con_id: 9 },
+        ]
+    );
+}
+
+#[test]
+fn stash_marks_focused_parent_container() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_window();
+        output.workspace(2).add_container(|split| {
+            split.focused().add_window().add_window();
+        });
+    });
+
+    let actions = workflow(&tree).stash();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MarkContainer {
+                con_id: 8,
+                mark: "_stash:2:8".to_owned()
+            },
+            Action::MoveContainerToScratchpad { con_id: 8 },
+        ]
+    );
+}
+
+#[test]
+fn stash_does_nothing_on_empty_workspace() {
+    let tree = single_output(|output| {
+        output.workspace(1).focused();
+    });
+
+    let actions = workflow(&tree).stash();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn unstash_next_prefers_window_stashed_from_focused_workspace() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(3).add_window();
+        });
+        root.stashed_window(3);
+        root.stashed_window(1);
+    });
+
+    let actions = workflow(&tree).unstash_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::ShowScratchpad { con_id: 10 },
+            Action::DisableFloating { con_id: 10 },
+            Action::MoveContainerById {
+                con_id: 10,
+                workspace_num: 2
+            },
+            Action::UnmarkContainer {
+                con_id: 10,
+                mark: "_stash:1:10".to_owned()
+            },
+            Action::FocusContainer { con_id: 10 },
+        ]
+    );
+}
+
+#[test]
+fn unstash_next_shifts_successors() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(2).add_window();
+        });
+        root.stashed_window(5);
+    });
+
+    let actions = workflow(&tree).unstash_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned()
+            },
+            Action::ShowScratchpad { con_id: 9 },
+            Action::DisableFloating { con_id: 9 },
+            Action::MoveContainerById {
+                con_id: 9,
+                workspace_num: 2
+            },
+            Action::UnmarkContainer {
+                con_id: 9,
+                mark: "_stash:5:9".to_owned()
+            },
+            Action::FocusContainer { con_id: 9 },
+        ]
+    );
+}
+
+#[test]
+fn unstash_next_skips_number_of_other_output_with_ranges() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.output("out-2", |output| {
+            output.workspace(2).add_window();
+        });
+        root.stashed_window(1);
+    });
+
+    let actions = workflow(&tree)
+        .with_output_ranges(vec!["out-1=1..=9".parse().unwrap()])
+        .unstash_next()
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::ShowScratchpad { con_id: 10 },
+            Action::DisableFloating { con_id: 10 },
+            Action::MoveContainerById {
+                con_id: 10,
+                workspace_num: 3
+            },
+            Action::UnmarkContainer {
+                con_id: 10,
+                mark: "_stash:1:10".to_owned()
+            },
+            Action::FocusContainer { con_id: 10 },
+        ]
+    );
+}
+
+#[test]
+fn unstash_next_shifts_own_successor_with_ranges() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(2).add_window();
+        });
+        root.output("out-2", |output| {
+            output.workspace(3).add_window();
+        });
+        root.stashed_window(1);
+    });
+
+    let actions = workflow(&tree)
+        .with_output_ranges(vec!["out-1=1..=9".parse().unwrap()])
+        .unstash_next()
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "4".to_owned()
+            },
+            Action::ShowScratchpad { con_id: 12 },
+            Action::DisableFloating { con_id: 12 },
+            Action::MoveContainerById {
+                con_id: 12,
+                workspace_num: 2
+            },
+            Action::UnmarkContainer {
+                con_id: 12,
+                mark: "_stash:1:12".to_owned()
+            },
+            Action::FocusContainer { con_id: 12 },
+        ]
+    );
+}
+
+#[test]
+fn unstash_next_fail_policy_ignores_own_successor() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+            output.workspace(2).add_window();
+        });
+        root.output("out-2", |output| {
+            output.workspace(4).add_window();
+        });
+        root.scratchpad_window();
+    });
+
+    let actions = workflow(&tree)
+        .with_conflict_policy(Some(ConflictPolicy::Fail))
+        .unstash_next();
+
+    assert_eq!(
+        actions,
+        Ok(vec![
+            Action::RenameWorkspace {
+                workspace_name: "2".to_owned(),
+                new_workspace_name: "3".to_owned()
+            },
+            Action::ShowScratchpad { con_id: 12 },
+            Action::DisableFloating { con_id: 12 },
+            Action::MoveContainerById {
+                con_id: 12,
+                workspace_num: 2
+            },
+            Action::FocusContainer { con_id: 12 },
+        ])
+    );
+}
+
+#[test]
+fn unstash_next_shows_window_before_moving_it() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.scratchpad_window();
+    });
+
+    let actions = workflow(&tree).unstash_next().unwrap();
+
+    let position = |wanted: &Action| actions.iter().position(|a| a == wanted);
+    let show = position(&Action::ShowScratchpad { con_id: 7 });
+    let tile = position(&Action::DisableFloating { con_id: 7 });
+    let moved = position(&Action::MoveContainerById {
+        con_id: 7,
+        workspace_num: 2,
+    });
+    assert!(show.is_some() && show < tile && tile < moved);
+}
+
+#[test]
+fn unstash_next_takes_unmarked_scratchpad_window() {
+    let tree = build(|root| {
+        root.output("out-1", |output| {
+            output.workspace(1).add_focused_window();
+        });
+        root.scratchpad_window();
+    });
+
+    let actions = workflow(&tree).unstash_next().unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::ShowScratchpad { con_id: 7 },
+            Action::DisableFloating { con_id: 7 },
+            Action::MoveContainerById {
+                con_id: 7,
+                workspace_num: 2
+            },
+            Action::FocusContainer { con_id: 7 },
+        ]
+    );
+}
+
+#[test]
+fn unstash_next_does_nothing_with_empty_scratchpad() {
+    let tree = single_output(|output| {
+        output.workspace(1).add_focused_window();
+    });
+
+    let actions = workflow(&tree).unstash_next().unwrap();
+
+    assert_eq!(actions, &[]);
+}
+
+fn workflow(tree: &Node) -> Workflow<impl Workspace + '_> {
+    get_workspaces_of(tree).map(Workflow::new).unwrap()
+}
@@ -0,0 +1,173 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, WorkspaceAssignments, get_workspaces_of};
+
+#[test]
+fn next_skips_number_assigned_to_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-2")
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn next_uses_number_assigned_to_current_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-1")
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn next_ignores_assignment_to_missing_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output DP-9")
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn next_skips_assigned_gap() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(4).add_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-2")
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn next_moves_to_existing_workspace_if_all_gaps_are_assigned() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window();
+            output_1.workspace(3).add_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-2")
+        .move_focus_to_next()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 3 }]);
+}
+
+#[test]
+fn move_next_skips_number_assigned_to_other_output() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_focused_window().add_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-2")
+        .move_container_to_next()
+        .unwrap();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::MoveContainer { workspace_num: 3 },
+            Action::MoveFocus { workspace_num: 3 }
+        ]
+    );
+}
+
+#[test]
+fn prev_skips_assigned_gap() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(1).add_window();
+            output_1.workspace(4).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 3 output out-2")
+        .move_focus_to_prev()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 2 }]);
+}
+
+#[test]
+fn prev_skips_assigned_number_before_first_workspace() {
+    let tree = two_outputs(
+        |output_1| {
+            output_1.workspace(3).add_focused_window();
+        },
+        |output_2| {
+            output_2.workspace(10).add_window();
+        },
+    );
+
+    let actions = with_config(&tree, "workspace 2 output out-2")
+        .move_focus_to_prev()
+        .unwrap();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 1 }]);
+}
+
+fn with_config<'a>(tree: &'a Node, config: &str) -> Workflow<impl Workspace + 'a> {
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_workspace_assignments(WorkspaceAssignments::parse(config))
+}
@@ -0,0 +1,159 @@
+/*
+ * This file is part of sway-workspace-extras
+ *
+ * Copyright (c) 2022 Thomas Himmelstoss
+ *
+ * This software is subject to the MIT license. You should have
+ * received a copy of the license along with this program.
+ */
+
+mod node_builder;
+
+use node_builder::{Node, two_outputs};
+use sway_workspace_extras::{Action, Workflow, Workspace, get_workspaces_of};
+
+const RANGES: &[&str] = &["out-1=11..=19", "out-2=21..=29"];
+
+#[test]
+fn group_goto_switches_other_outputs_first() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(11).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, RANGES).focus_group(2);
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusOutput {
+                output_name: "out-2".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 22 },
+            Action::FocusOutput {
+                output_name: "out-1".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 12 },
+        ]
+    );
+}
+
+#[test]
+fn group_next_uses_position_in_range_of_focused_output() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+        |output| {
+            output.workspace(12).visible().add_focused_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=21..=29", "out-2=11..=19"]).focus_next_group();
+
+    assert_eq!(
+        actions,
+        &[
+            Action::FocusOutput {
+                output_name: "out-1".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 23 },
+            Action::FocusOutput {
+                output_name: "out-2".to_owned()
+            },
+            Action::MoveFocus { workspace_num: 13 },
+        ]
+    );
+}
+
+#[test]
+fn group_prev_skips_output_already_showing_group() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(12).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, RANGES).focus_prev_group();
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 11 }]);
+}
+
+#[test]
+fn group_prev_does_nothing_at_first_group() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(11).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(22).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, RANGES).focus_prev_group();
+
+    assert_eq!(actions, &[]);
+}
+
+#[test]
+fn group_beyond_range_of_output_is_skipped() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(11).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=11..=19", "out-2=21..=22"]).focus_group(3);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 13 }]);
+}
+
+#[test]
+fn outputs_without_range_are_not_switched() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(11).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-1=11..=19"]).focus_group(2);
+
+    assert_eq!(actions, &[Action::MoveFocus { workspace_num: 12 }]);
+}
+
+#[test]
+fn group_next_without_range_of_focused_output_does_nothing() {
+    let tree = two_outputs(
+        |output| {
+            output.workspace(1).visible().add_focused_window();
+        },
+        |output| {
+            output.workspace(21).visible().add_window();
+        },
+    );
+
+    let actions = with_ranges(&tree, &["out-2=21..=29"]).focus_next_group();
+
+    assert_eq!(actions, &[]);
+}
+
+fn with_ranges<'a>(tree: &'a Node, ranges: &[&str]) -> Workflow<impl Workspace + 'a> {
+    let ranges = ranges.iter().map(|r| r.parse().unwrap()).collect();
+    get_workspaces_of(tree)
+        .map(Workflow::new)
+        .unwrap()
+        .with_output_ranges(ranges)
+}